@@ -0,0 +1,119 @@
+use crate::browser::{error, log, BrowserRef, LogLevel};
+use crate::sdk_prelude::*;
+use api_derive::ApiType;
+use serde_derive::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+use std::sync::Mutex;
+use ton_client::crypto::{remove_encryption_box, RegisteredEncryptionBox};
+
+/// Handles of signing and encryption boxes owned by DeBot engine.
+#[derive(Serialize, Deserialize, Clone, Debug, ApiType, Default)]
+pub struct LiveBoxes {
+    /// Signing boxes created by engine or received from browser.
+    pub signing_boxes: Vec<SigningBoxHandle>,
+    /// Encryption boxes created by engine or received from browser.
+    pub encryption_boxes: Vec<EncryptionBoxHandle>,
+}
+
+/// Registry of signing and encryption boxes which are owned by DeBot engine.
+///
+/// Boxes are registered in SDK context of the engine and must be released
+/// explicitly with `release_all` when DeBot is removed.
+pub(crate) struct BoxRegistry {
+    ton: TonClient,
+    browser: BrowserRef,
+    signing_boxes: Mutex<BTreeSet<u32>>,
+    encryption_boxes: Mutex<BTreeSet<u32>>,
+}
+
+impl BoxRegistry {
+    pub fn new(ton: TonClient, browser: BrowserRef) -> Self {
+        Self {
+            ton,
+            browser,
+            signing_boxes: Mutex::new(BTreeSet::new()),
+            encryption_boxes: Mutex::new(BTreeSet::new()),
+        }
+    }
+
+    pub fn add_signing_box(&self, handle: &SigningBoxHandle) {
+        self.signing_boxes.lock().unwrap().insert(handle.0);
+    }
+
+    pub fn add_encryption_box(&self, handle: &EncryptionBoxHandle) {
+        self.encryption_boxes.lock().unwrap().insert(handle.0);
+    }
+
+    /// Removes signing box from SDK context. Returns false if box is not owned by engine.
+    pub fn release_signing_box(&self, handle: &SigningBoxHandle) -> bool {
+        let removed = self.signing_boxes.lock().unwrap().remove(&handle.0);
+        if removed {
+            self.remove_signing_box(handle.0);
+        }
+        removed
+    }
+
+    /// Removes encryption box from SDK context. Returns false if box is not owned by engine.
+    pub fn release_encryption_box(&self, handle: &EncryptionBoxHandle) -> bool {
+        let removed = self.encryption_boxes.lock().unwrap().remove(&handle.0);
+        if removed {
+            self.remove_encryption_box(handle.0);
+        }
+        removed
+    }
+
+    /// Removes all boxes owned by engine from SDK context.
+    pub fn release_all(&self) {
+        let signing_boxes = std::mem::take(&mut *self.signing_boxes.lock().unwrap());
+        for handle in signing_boxes {
+            self.remove_signing_box(handle);
+        }
+        let encryption_boxes = std::mem::take(&mut *self.encryption_boxes.lock().unwrap());
+        for handle in encryption_boxes {
+            self.remove_encryption_box(handle);
+        }
+    }
+
+    pub fn live_boxes(&self) -> LiveBoxes {
+        LiveBoxes {
+            signing_boxes: self
+                .signing_boxes
+                .lock()
+                .unwrap()
+                .iter()
+                .map(|h| SigningBoxHandle(*h))
+                .collect(),
+            encryption_boxes: self
+                .encryption_boxes
+                .lock()
+                .unwrap()
+                .iter()
+                .map(|h| EncryptionBoxHandle(*h))
+                .collect(),
+        }
+    }
+
+    fn remove_signing_box(&self, handle: u32) {
+        let result = remove_signing_box(
+            self.ton.clone(),
+            RegisteredSigningBox {
+                handle: SigningBoxHandle(handle),
+            },
+        );
+        if let Err(e) = result {
+            error!(self.browser, "failed to release signing box {}: {}", handle, e);
+        }
+    }
+
+    fn remove_encryption_box(&self, handle: u32) {
+        let result = remove_encryption_box(
+            self.ton.clone(),
+            RegisteredEncryptionBox {
+                handle: EncryptionBoxHandle(handle),
+            },
+        );
+        if let Err(e) = result {
+            error!(self.browser, "failed to release encryption box {}: {}", handle, e);
+        }
+    }
+}
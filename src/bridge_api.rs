@@ -98,6 +98,17 @@ pub async fn fetch(
 pub struct ParamsOfInit {
     /// Debot smart contract address
     pub address: String,
+    /// User wallet used to send internal messages on behalf of DeBot.
+    pub wallet: Option<WalletConfig>,
+    /// User identity returned to DeBot by UserInfo interface.
+    pub profile: Option<UserProfile>,
+    /// Restrictions on network requests made by DeBot. By default all requests are allowed.
+    pub network_policy: Option<NetworkPolicy>,
+    /// Storage of Storage interface. If not set, DeBot can't store data.
+    pub storage: Option<StorageConfig>,
+    /// Source of user keys for SigningBoxInput and EncryptionBoxInput interfaces.
+    /// If not set, these interfaces are implemented by browser.
+    pub key_provider: Option<KeyProviderConfig>,
 }
 
 ///  Structure for storing debot handle returned from `init` function.
@@ -122,6 +133,18 @@ pub async fn init(
     context: Arc<DengineContext>,
     params: ParamsOfInit,
     callbacks: impl BrowserCallbacks + Send + Sync + 'static,
+) -> ClientResult<RegisteredDebot> {
+    init_with_app_providers(context, params, callbacks, None, None).await
+}
+
+/// Same as `init`. `app_storage` is used if storage backend is `App`,
+/// `app_keys` is used if key provider is `App`.
+pub(crate) async fn init_with_app_providers(
+    context: Arc<DengineContext>,
+    params: ParamsOfInit,
+    callbacks: impl BrowserCallbacks + Send + Sync + 'static,
+    app_storage: Option<StorageProviderRef>,
+    app_keys: Option<KeyProviderRef>,
 ) -> ClientResult<RegisteredDebot> {
     let mut dengine = DEngine::new(
         params.address,
@@ -129,10 +152,43 @@ pub async fn init(
         context.endpoints.clone(),
         Arc::new(callbacks),
     );
+    dengine.set_wallet(params.wallet);
+    if let Some(profile) = params.profile {
+        dengine.set_user_profile(profile);
+    }
+    if let Some(policy) = params.network_policy {
+        dengine.set_network_policy(policy);
+    }
+    if let Some(config) = params.storage {
+        let storage: StorageProviderRef = match config.backend {
+            StorageBackend::Memory => Arc::new(MemoryStorageProvider::new()),
+            StorageBackend::File { path } => context.file_storage(&path),
+            StorageBackend::App => app_storage.ok_or_else(|| {
+                Error::storage_failed("storage can be routed to application only in JSON API")
+            })?,
+        };
+        dengine.set_storage(storage, config.quota.unwrap_or(DEFAULT_STORAGE_QUOTA));
+    }
+    if let Some(config) = params.key_provider {
+        let keys: KeyProviderRef = match config {
+            KeyProviderConfig::KeyPairs { keys } => {
+                Arc::new(KeyPairProvider::new(dengine.client(), keys))
+            }
+            KeyProviderConfig::App => app_keys.ok_or_else(|| {
+                Error::invalid_json_params(
+                    "key requests can be routed to application only in JSON API",
+                )
+            })?,
+        };
+        dengine.set_key_provider(keys);
+    }
     let info: DebotInfo = dengine.init().await.map_err(Error::fetch_failed)?.into();
 
     let handle = context.get_next_id();
+    let events = dengine.events();
+    context.subscriptions.insert(handle, dengine.subscriptions());
     context.debots.insert(handle, Mutex::new(dengine));
+    dispatch_events(context.clone(), handle, events);
     let debot_abi = info.dabi.clone().unwrap_or(String::new());
     Ok(RegisteredDebot {
         debot_handle: DebotHandle(handle),
@@ -141,6 +197,21 @@ pub async fn init(
     })
 }
 
+/// Handles subscription updates and delayed answers of DeBot until it is removed.
+fn dispatch_events(context: Arc<DengineContext>, handle: u32, events: EventReceiver) {
+    context.clone().spawn(async move {
+        while let Some(event) = events.next().await {
+            let mutex = match context.debots.get(&handle) {
+                Some(mutex) => mutex,
+                None => break,
+            };
+            let mut dengine = mutex.1.lock().await;
+            // Errors are reported to browser by engine.
+            let _ = dengine.handle_event(event).await;
+        }
+    });
+}
+
 ///
 #[derive(Serialize, Deserialize, ApiType, Default)]
 pub struct ParamsOfRemove {
@@ -151,13 +222,46 @@ pub struct ParamsOfRemove {
 ///  Destroys debot handle.
 ///
 /// Removes handle from Client Context and drops debot engine referenced by that handle.
+/// Waits until engine finishes current operation, cancels its subscriptions
+/// and releases its signing and encryption boxes.
 //#[wasm_bindgen]
 #[api_function]
-pub fn remove(context: Arc<DengineContext>, params: ParamsOfRemove) -> ClientResult<()> {
-    context.debots.remove(&params.debot_handle.0);
+pub async fn remove(context: Arc<DengineContext>, params: ParamsOfRemove) -> ClientResult<()> {
+    let subscriptions = context.subscriptions.remove(&params.debot_handle.0);
+    if let Some(removed) = context.debots.remove(&params.debot_handle.0) {
+        let dengine = removed.1.lock().await;
+        dengine.unsubscribe_all().await;
+        dengine.release_boxes();
+    }
+    if let Some(removed) = subscriptions {
+        removed.1.close();
+    }
     Ok(())
 }
 
+///  Parameters of `get_boxes` function.
+#[derive(Serialize, Deserialize, ApiType, Default)]
+pub struct ParamsOfGetBoxes {
+    /// Debot handle which references an instance of debot engine.
+    pub debot_handle: DebotHandle,
+}
+
+///  Returns signing and encryption boxes owned by debot engine.
+///
+/// Boxes are removed from client context when debot is removed.
+#[api_function]
+pub async fn get_boxes(
+    context: Arc<DengineContext>,
+    params: ParamsOfGetBoxes,
+) -> ClientResult<LiveBoxes> {
+    let mutex = context
+        .debots
+        .get(&params.debot_handle.0)
+        .ok_or(Error::invalid_handle(params.debot_handle.0))?;
+    let dengine = mutex.1.lock().await;
+    Ok(dengine.live_boxes())
+}
+
 ///  Parameters of `send` function.
 #[derive(Serialize, Deserialize, ApiType, Default)]
 pub struct ParamsOfSend {
@@ -180,3 +284,91 @@ pub async fn send(context: Arc<DengineContext>, params: ParamsOfSend) -> ClientR
     let mut dengine = mutex.1.lock().await;
     dengine.send(params.message).await
 }
+
+///  Parameters of `send_collection_event` function.
+#[derive(Serialize, Deserialize, ApiType, Default)]
+pub struct ParamsOfSendCollectionEvent {
+    /// Debot handle which references an instance of debot engine.
+    pub debot_handle: DebotHandle,
+    /// Collection update received by subscription.
+    pub event: CollectionEvent,
+}
+
+///  Delivers collection update to DeBot subscription.
+///
+/// Used by Debot Browser to pass updates of subscriptions created by
+/// `SubscribeCollection` callback. Updates of cancelled subscriptions are ignored.
+#[api_function]
+pub fn send_collection_event(
+    context: Arc<DengineContext>,
+    params: ParamsOfSendCollectionEvent,
+) -> ClientResult<()> {
+    let subscriptions = context
+        .subscriptions
+        .get(&params.debot_handle.0)
+        .ok_or(Error::invalid_handle(params.debot_handle.0))?;
+    subscriptions.1.push(params.event);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::executor::block_on;
+    use serde_json::json;
+
+    const DEBOT: &str = "0:1111111111111111111111111111111111111111111111111111111111111111";
+
+    /// Browser which only records cancelled subscriptions.
+    #[derive(Default)]
+    struct MockBrowser {
+        unsubscribed: std::sync::Mutex<Vec<u32>>,
+    }
+
+    #[async_trait::async_trait]
+    impl BrowserCallbacks for MockBrowser {
+        fn log(&self, _level: LogLevel, _msg: String) {}
+        async fn send(&self, _message: String) {}
+        async fn unsubscribe_collection(&self, subscription_id: u32) -> ClientResult<()> {
+            self.unsubscribed.lock().unwrap().push(subscription_id);
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_remove_cancels_subscriptions() {
+        let browser = Arc::new(MockBrowser::default());
+        let ton = Arc::new(ClientContext::new(ClientConfig::default()).unwrap());
+        let dengine = DEngine::new_with_client(DEBOT.to_owned(), None, ton, browser.clone());
+        let subscriptions = dengine.subscriptions();
+        let first = subscriptions.add(1).subscription_id();
+        let second = subscriptions.add(2).subscription_id();
+
+        let context = Arc::new(DengineContext::new(None, None).unwrap());
+        context.subscriptions.insert(7, subscriptions.clone());
+        context.debots.insert(7, Mutex::new(dengine));
+        block_on(remove(
+            context.clone(),
+            ParamsOfRemove {
+                debot_handle: DebotHandle(7),
+            },
+        ))
+        .unwrap();
+
+        let mut unsubscribed = browser.unsubscribed.lock().unwrap().clone();
+        unsubscribed.sort();
+        assert_eq!(unsubscribed, vec![first, second]);
+        assert!(subscriptions.ids().is_empty());
+        assert!(!subscriptions.push_answer(String::new(), 0, json!({})));
+        assert!(context.debots.get(&7).is_none());
+        assert!(context.subscriptions.get(&7).is_none());
+    }
+
+    #[test]
+    fn test_file_storage_is_shared() {
+        let context = DengineContext::new(None, None).unwrap();
+        let storage = context.file_storage("storage.json");
+        assert!(Arc::ptr_eq(&storage, &context.file_storage("./storage.json")));
+        assert!(!Arc::ptr_eq(&storage, &context.file_storage("other.json")));
+    }
+}
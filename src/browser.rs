@@ -1,5 +1,8 @@
 use crate::action::DAction;
 use crate::activity::DebotActivity;
+use crate::errors::Error;
+use crate::network_policy::NetworkPolicy;
+use crate::subscriptions::EventSink;
 use crate::sdk_prelude::*;
 use api_derive::ApiType;
 use serde_derive::{Deserialize, Serialize};
@@ -32,6 +35,11 @@ macro_rules! error {
 
 pub(crate) use {debug, log, error};
 
+/// Error returned by default implementations of optional browser callbacks.
+fn not_supported(callback: &str) -> ClientError {
+    Error::browser_callback_failed(format!("{callback} is not supported by browser"))
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, ApiType, Default)]
 pub struct FetchHeader {
     pub key: String,
@@ -45,6 +53,50 @@ pub struct FetchResponse {
     pub content: String,
 }
 
+/// Menu item shown by Menu interface.
+#[derive(Serialize, Deserialize, Debug, Clone, ApiType, Default)]
+pub struct MenuItem {
+    /// Item title.
+    pub title: String,
+    /// Item description.
+    pub description: String,
+    /// ID of DeBot function which is called when item is selected.
+    pub handler_id: u32,
+}
+
+/// Kind of value requested by DateTimeInput interface.
+#[derive(Serialize, Deserialize, Debug, Clone, ApiType, Default, PartialEq)]
+pub enum DateTimeKind {
+    /// Date in "YYYY-MM-DD" format.
+    #[default]
+    Date,
+    /// Time in "HH:MM" format.
+    Time,
+    /// Date and time in "YYYY-MM-DD HH:MM" format.
+    DateTime,
+}
+
+/// Http(s) request with binary body (Network interface v2).
+#[derive(Serialize, Deserialize, Debug, Clone, ApiType, Default)]
+pub struct FetchRequest {
+    pub url: String,
+    /// Http method in upper case, e.g. "PUT".
+    pub method: String,
+    pub headers: Vec<FetchHeader>,
+    /// Request body encoded as base64.
+    pub body: Option<String>,
+    /// Request timeout in milliseconds. Engine stops waiting for response
+    /// after timeout, browser can use it to abort request.
+    pub timeout_ms: Option<u32>,
+    /// Maximal size of response content in bytes. Engine rejects larger
+    /// responses, browser can use it to stop downloading.
+    pub max_response_size: Option<u32>,
+    /// Network policy of DeBot. `url` is already checked by engine,
+    /// browser can use policy to limit redirects.
+    #[serde(default)]
+    pub policy: Option<NetworkPolicy>,
+}
+
 #[derive(Serialize, Deserialize, ApiType, Default, Debug, Clone)]
 pub struct WaitForTransactionParams {
     pub abi: Option<Abi>,
@@ -56,69 +108,231 @@ pub struct WaitForTransactionParams {
 }
 
 /// Callbacks that are called by debot engine to communicate with Debot Browser.
+///
+/// Only `log` and `send` are required. Other callbacks return "not supported" error
+/// by default, the error is reported to DeBot by the interface which needs the callback.
 #[async_trait::async_trait]
 pub trait BrowserCallbacks {
     /// Prints text message to user.
     fn log(&self, level: LogLevel, msg: String);
     /// Requests keys from user.
-    async fn get_signing_box(&self) -> Result<SigningBoxHandle, String>;
+    async fn get_signing_box(&self) -> Result<SigningBoxHandle, String> {
+        Err(not_supported("get_signing_box").to_string())
+    }
     /// Sends message with debot interface call to Browser.
     /// Message parameter is a BoC encoded as Base64.
     async fn send(&self, message: String);
     /// Requests permission to execute DeBot operation
     /// (e.g. sending messages to blockchain).
-    async fn approve(&self, activity: DebotActivity) -> ClientResult<bool>;
+    async fn approve(&self, _activity: DebotActivity) -> ClientResult<bool> {
+        Err(not_supported("approve"))
+    }
     /// Network http(s) request
     async fn fetch(
         &self,
-        url: String,
-        method: String,
-        headers: Vec<FetchHeader>,
-        body: Option<String>,
-    ) -> ClientResult<FetchResponse>;
+        _url: String,
+        _method: String,
+        _headers: Vec<FetchHeader>,
+        _body: Option<String>,
+    ) -> ClientResult<FetchResponse> {
+        Err(not_supported("fetch"))
+    }
+    /// Network http(s) request with binary body (Network interface v2).
+    /// Response content must be encoded as base64.
+    ///
+    /// Default implementation sends request with `fetch`, so request body
+    /// must be valid utf8 string.
+    async fn fetch_binary(&self, request: FetchRequest) -> ClientResult<FetchResponse> {
+        let body = match request.body {
+            Some(body) => {
+                let body = base64::decode(body)
+                    .map_err(|e| Error::browser_callback_failed(format!("invalid body: {e}")))?;
+                Some(String::from_utf8(body).map_err(|_| {
+                    Error::browser_callback_failed("binary request body is not supported")
+                })?)
+            }
+            None => None,
+        };
+        let response = self
+            .fetch(request.url, request.method, request.headers, body)
+            .await?;
+        Ok(FetchResponse {
+            content: base64::encode(&response.content),
+            ..response
+        })
+    }
     /// Data encryption.
     /// data - encoded as base64.
     /// Result - encrypted string as base64.
-    async fn encrypt(&self, handle: EncryptionBoxHandle, data: String) -> ClientResult<String>;
+    async fn encrypt(&self, _handle: EncryptionBoxHandle, _data: String) -> ClientResult<String> {
+        Err(not_supported("encrypt"))
+    }
     /// Data decryption
-    async fn decrypt(&self, handle: EncryptionBoxHandle, data: String) -> ClientResult<String>;
+    async fn decrypt(&self, _handle: EncryptionBoxHandle, _data: String) -> ClientResult<String> {
+        Err(not_supported("decrypt"))
+    }
     /// Data signing
     /// data - string with data to sign encoded as base64.
-    async fn sign(&self, handle: SigningBoxHandle, data: String) -> ClientResult<String>;
+    async fn sign(&self, _handle: SigningBoxHandle, _data: String) -> ClientResult<String> {
+        Err(not_supported("sign"))
+    }
     /// Send external incoming message to blockchain
     /// message - base64 string with serialized message.
-    async fn send_message(&self, message: String) -> ClientResult<ResultOfSendMessage>;
-    async fn query(&self, params: ParamsOfQuery) -> ClientResult<ResultOfQuery>;
+    async fn send_message(&self, _message: String) -> ClientResult<ResultOfSendMessage> {
+        Err(not_supported("send_message"))
+    }
+    async fn query(&self, _params: ParamsOfQuery) -> ClientResult<ResultOfQuery> {
+        Err(not_supported("query"))
+    }
     async fn query_collection(
         &self,
-        params: ParamsOfQueryCollection,
-    ) -> ClientResult<ResultOfQueryCollection>;
+        _params: ParamsOfQueryCollection,
+    ) -> ClientResult<ResultOfQueryCollection> {
+        Err(not_supported("query_collection"))
+    }
     async fn wait_for_collection(
         &self,
-        params: ParamsOfWaitForCollection,
-    ) -> ClientResult<ResultOfWaitForCollection>;
+        _params: ParamsOfWaitForCollection,
+    ) -> ClientResult<ResultOfWaitForCollection> {
+        Err(not_supported("wait_for_collection"))
+    }
+    async fn aggregate_collection(
+        &self,
+        _params: ParamsOfAggregateCollection,
+    ) -> ClientResult<ResultOfAggregateCollection> {
+        Err(not_supported("aggregate_collection"))
+    }
+    /// Subscribes to collection updates (Query interface). Browser sends updates to
+    /// `events` until `unsubscribe_collection` is called with `events.subscription_id()`.
+    async fn subscribe_collection(
+        &self,
+        _params: ParamsOfSubscribeCollection,
+        _events: EventSink,
+    ) -> ClientResult<()> {
+        Err(not_supported("subscribe_collection"))
+    }
+    /// Cancels subscription created by `subscribe_collection`.
+    async fn unsubscribe_collection(&self, _subscription_id: u32) -> ClientResult<()> {
+        Err(not_supported("unsubscribe_collection"))
+    }
     async fn wait_for_transaction(
         &self,
-        params: WaitForTransactionParams,
-    ) -> ClientResult<ResultOfProcessMessage>;
+        _params: WaitForTransactionParams,
+    ) -> ClientResult<ResultOfProcessMessage> {
+        Err(not_supported("wait_for_transaction"))
+    }
     async fn query_transaction_tree(
         &self,
-        params: ParamsOfQueryTransactionTree,
-    ) -> ClientResult<ResultOfQueryTransactionTree>;
-    async fn get_signing_box_info(&self, handle: SigningBoxHandle) -> ClientResult<String>;
+        _params: ParamsOfQueryTransactionTree,
+    ) -> ClientResult<ResultOfQueryTransactionTree> {
+        Err(not_supported("query_transaction_tree"))
+    }
+    async fn get_signing_box_info(&self, _handle: SigningBoxHandle) -> ClientResult<String> {
+        Err(not_supported("get_signing_box_info"))
+    }
     async fn get_encryption_box_info(
         &self,
-        handle: EncryptionBoxHandle,
-    ) -> ClientResult<EncryptionBoxInfo>;
+        _handle: EncryptionBoxHandle,
+    ) -> ClientResult<EncryptionBoxInfo> {
+        Err(not_supported("get_encryption_box_info"))
+    }
+    /// Prints DeBot message to user (Terminal interface).
+    async fn print(&self, _message: String) -> ClientResult<()> {
+        Err(not_supported("print"))
+    }
+    /// Requests text from user (Terminal interface).
+    /// If `multiline` is true, user can enter several lines of text.
+    async fn input_text(&self, _prompt: String, _multiline: bool) -> ClientResult<String> {
+        Err(not_supported("input_text"))
+    }
+    /// Shows menu to user (Menu interface).
+    /// Returns index of the selected item.
+    async fn select_menu(
+        &self,
+        _title: String,
+        _description: String,
+        _items: Vec<MenuItem>,
+    ) -> ClientResult<u32> {
+        Err(not_supported("select_menu"))
+    }
+    /// Requests address from user (AddressInput interface).
+    /// Address is validated by engine.
+    async fn input_address(&self, _prompt: String) -> ClientResult<String> {
+        Err(not_supported("input_address"))
+    }
+    /// Requests user to select one of the user's wallets (AddressInput interface).
+    /// Returns wallet address.
+    async fn select_wallet(&self) -> ClientResult<String> {
+        Err(not_supported("select_wallet"))
+    }
+    /// Requests amount from user (AmountInput interface).
+    /// Amount is a decimal string with at most `decimals` digits after point,
+    /// `min` and `max` are amount limits in minimal units. Amount is validated by engine.
+    async fn input_amount(
+        &self,
+        _prompt: String,
+        _decimals: u8,
+        _min: String,
+        _max: String,
+    ) -> ClientResult<String> {
+        Err(not_supported("input_amount"))
+    }
+    /// Requests confirmation from user (ConfirmInput interface).
+    async fn confirm(&self, _prompt: String) -> ClientResult<bool> {
+        Err(not_supported("confirm"))
+    }
+    /// Requests integer number from user (NumberInput interface).
+    /// Number is validated by engine.
+    async fn input_number(
+        &self,
+        _prompt: String,
+        _min: String,
+        _max: String,
+    ) -> ClientResult<String> {
+        Err(not_supported("input_number"))
+    }
+    /// Requests date and/or time from user (DateTimeInput interface).
+    /// `default`, `min`, `max` and returned value are strings in format defined by `kind`
+    /// in user time zone. Value is validated by engine.
+    async fn input_datetime(
+        &self,
+        _prompt: String,
+        _kind: DateTimeKind,
+        _default: String,
+        _min: String,
+        _max: String,
+    ) -> ClientResult<String> {
+        Err(not_supported("input_datetime"))
+    }
+    /// Returns user time zone offset in minutes east of UTC (DateTimeInput interface).
+    async fn get_time_zone_offset(&self) -> ClientResult<i16> {
+        Err(not_supported("get_time_zone_offset"))
+    }
+    /// Requests user to choose one of the keys (SigningBoxInput and EncryptionBoxInput
+    /// interfaces). Returns selected public key.
+    async fn select_key(&self, _prompt: String, _public_keys: Vec<String>) -> ClientResult<String> {
+        Err(not_supported("select_key"))
+    }
+    /// Scans QR code with camera (QRCode interface). Returns QR code content.
+    async fn scan_qr_code(&self, _prompt: String) -> ClientResult<String> {
+        Err(not_supported("scan_qr_code"))
+    }
+    /// Shows media content to user (Media interface).
+    /// `data` is a data URL with base64 payload validated by engine.
+    async fn output_media(&self, _prompt: String, _data: String) -> ClientResult<()> {
+        Err(not_supported("output_media"))
+    }
 
     /// [Deprecated]
-    async fn switch(&self, ctx_id: u8);
+    async fn switch(&self, _ctx_id: u8) {}
     /// [Deprecated]
-    async fn switch_completed(&self);
+    async fn switch_completed(&self) {}
     /// [Deprecated]
-    async fn show_action(&self, act: DAction);
+    async fn show_action(&self, _act: DAction) {}
     /// [Deprecated]
-    async fn input(&self, prompt: &str, value: &mut String);
+    async fn input(&self, _prompt: &str, _value: &mut String) {}
     /// [Deprecated]
-    async fn invoke_debot(&self, debot: String, action: DAction) -> Result<(), String>;
+    async fn invoke_debot(&self, _debot: String, _action: DAction) -> Result<(), String> {
+        Err(not_supported("invoke_debot").to_string())
+    }
 }
@@ -0,0 +1,384 @@
+use super::dinterface::{decode_answer_id, get_arg, get_bool_arg, DebotInterface, InterfaceResult};
+use super::json_lib_utils::{pack, Value};
+use crate::common::Error;
+use crate::dengine::EMPTY_CELL;
+use crate::sdk_prelude::*;
+use serde_json::json;
+use ton_client::abi::{
+    decode_account_data, encode_internal_message, MessageBodyType, ParamsOfDecodeAccountData,
+    ParamsOfEncodeInternalMessage,
+};
+
+const ABI: &str = r#"
+{
+	"ABI version": 2,
+	"version": "2.2",
+	"header": ["time"],
+	"functions": [
+		{
+			"name": "encodeMessageBody",
+			"inputs": [
+				{"name":"answerId","type":"uint32"},
+				{"name":"abi","type":"string"},
+				{"name":"function","type":"string"},
+				{"name":"paramsJson","type":"string"},
+				{"name":"isInternal","type":"bool"}
+			],
+			"outputs": [
+				{"name":"result","type":"uint32"},
+				{"name":"body","type":"cell"}
+			]
+		},
+		{
+			"name": "decodeMessageBody",
+			"inputs": [
+				{"name":"answerId","type":"uint32"},
+				{"name":"abi","type":"string"},
+				{"name":"body","type":"cell"},
+				{"name":"isInternal","type":"bool"}
+			],
+			"outputs": [
+				{"name":"result","type":"uint32"},
+				{"name":"bodyType","type":"uint8"},
+				{"name":"name","type":"string"},
+				{"components":[{"name":"kind","type":"uint8"},{"name":"value","type":"cell"},{"name":"object","type":"map(uint256,cell)"},{"components":[{"name":"cell","type":"cell"}],"name":"array","type":"tuple[]"}],"name":"value","type":"tuple"}
+			]
+		},
+		{
+			"name": "encodeInternalMessage",
+			"inputs": [
+				{"name":"answerId","type":"uint32"},
+				{"name":"abi","type":"string"},
+				{"name":"dest","type":"address"},
+				{"name":"function","type":"string"},
+				{"name":"paramsJson","type":"string"},
+				{"name":"value","type":"uint128"},
+				{"name":"bounce","type":"bool"}
+			],
+			"outputs": [
+				{"name":"result","type":"uint32"},
+				{"name":"message","type":"cell"}
+			]
+		},
+		{
+			"name": "decodeAccountData",
+			"inputs": [
+				{"name":"answerId","type":"uint32"},
+				{"name":"abi","type":"string"},
+				{"name":"data","type":"cell"}
+			],
+			"outputs": [
+				{"name":"result","type":"uint32"},
+				{"components":[{"name":"kind","type":"uint8"},{"name":"value","type":"cell"},{"name":"object","type":"map(uint256,cell)"},{"components":[{"name":"cell","type":"cell"}],"name":"array","type":"tuple[]"}],"name":"value","type":"tuple"}
+			]
+		},
+		{
+			"name": "decodeEvent",
+			"inputs": [
+				{"name":"answerId","type":"uint32"},
+				{"name":"abi","type":"string"},
+				{"name":"message","type":"cell"}
+			],
+			"outputs": [
+				{"name":"result","type":"uint32"},
+				{"name":"name","type":"string"},
+				{"components":[{"name":"kind","type":"uint8"},{"name":"value","type":"cell"},{"name":"object","type":"map(uint256,cell)"},{"components":[{"name":"cell","type":"cell"}],"name":"array","type":"tuple[]"}],"name":"value","type":"tuple"}
+			]
+		},
+		{
+			"name": "constructor",
+			"inputs": [
+			],
+			"outputs": [
+			]
+		}
+	],
+	"data": [
+	],
+	"events": [
+	],
+	"fields": [
+		{"name":"_pubkey","type":"uint256"},
+		{"name":"_timestamp","type":"uint64"},
+		{"name":"_constructorFlag","type":"bool"}
+	]
+}
+"#;
+
+const ABI_ID: &str = "da91f3f9132185a406508185c35b200b6ea480f573bc142317c42eada437fcda";
+
+/// Abi interface. Encodes and decodes messages and data of arbitrary contracts.
+///
+/// ABI is passed as JSON string, function parameters as JSON object string.
+/// Decoded values are returned as Json interface `Value` trees.
+/// `result` is 0 on success, otherwise SDK error code.
+/// `encodeInternalMessage` with empty function name encodes message without body.
+pub struct AbiInterface {
+    ton: TonClient,
+}
+
+impl AbiInterface {
+    pub fn new(ton: TonClient) -> Self {
+        Self { ton }
+    }
+
+    async fn encode_message_body(&self, args: &JsonValue) -> InterfaceResult {
+        let answer_id = decode_answer_id(args)?;
+        let abi = get_arg(args, "abi")?;
+        let function = get_arg(args, "function")?;
+        let params = get_arg(args, "paramsJson")?;
+        let is_internal = get_bool_arg(args, "isInternal")?;
+        let call_set = parse_params(&params).and_then(|input| {
+            call_set(&function, input)
+                .ok_or_else(|| Error::invalid_json_params("function name is empty"))
+        });
+        let result = match call_set {
+            Ok(call_set) => encode_message_body(
+                self.ton.clone(),
+                ParamsOfEncodeMessageBody {
+                    abi: Abi::Json(abi),
+                    call_set,
+                    is_internal,
+                    signer: Signer::None,
+                    ..Default::default()
+                },
+            )
+            .await
+            .map(|r| r.body),
+            Err(e) => Err(e),
+        };
+        let (result, body) = match result {
+            Ok(body) => (0, body),
+            Err(e) => (e.code, EMPTY_CELL.to_owned()),
+        };
+        Ok((answer_id, json!({ "result": result, "body": body })))
+    }
+
+    fn decode_message_body(&self, args: &JsonValue) -> InterfaceResult {
+        let answer_id = decode_answer_id(args)?;
+        let result = decode_message_body(
+            self.ton.clone(),
+            ParamsOfDecodeMessageBody {
+                abi: Abi::Json(get_arg(args, "abi")?),
+                body: get_arg(args, "body")?,
+                is_internal: get_bool_arg(args, "isInternal")?,
+                ..Default::default()
+            },
+        )
+        .and_then(|decoded| {
+            let value = pack_value(decoded.value)?;
+            Ok((body_type_code(&decoded.body_type), decoded.name, value))
+        });
+        let (result, body_type, name, value) = match result {
+            Ok((body_type, name, value)) => (0, body_type, name, value),
+            Err(e) => (e.code, 0, String::new(), Value::default()),
+        };
+        Ok((
+            answer_id,
+            json!({ "result": result, "bodyType": body_type, "name": name, "value": value }),
+        ))
+    }
+
+    fn encode_internal_message(&self, args: &JsonValue) -> InterfaceResult {
+        let answer_id = decode_answer_id(args)?;
+        let abi = get_arg(args, "abi")?;
+        let dest = get_arg(args, "dest")?;
+        let function = get_arg(args, "function")?;
+        let params = get_arg(args, "paramsJson")?;
+        let value = get_arg(args, "value")?;
+        let bounce = get_bool_arg(args, "bounce")?;
+        let result = parse_params(&params).and_then(|input| {
+            encode_internal_message(
+                self.ton.clone(),
+                ParamsOfEncodeInternalMessage {
+                    abi: Some(Abi::Json(abi)),
+                    address: Some(dest),
+                    call_set: call_set(&function, input),
+                    value,
+                    bounce: Some(bounce),
+                    ..Default::default()
+                },
+            )
+        });
+        let (result, message) = match result {
+            Ok(r) => (0, r.message),
+            Err(e) => (e.code, EMPTY_CELL.to_owned()),
+        };
+        Ok((answer_id, json!({ "result": result, "message": message })))
+    }
+
+    fn decode_account_data(&self, args: &JsonValue) -> InterfaceResult {
+        let answer_id = decode_answer_id(args)?;
+        let result = decode_account_data(
+            self.ton.clone(),
+            ParamsOfDecodeAccountData {
+                abi: Abi::Json(get_arg(args, "abi")?),
+                data: get_arg(args, "data")?,
+                allow_partial: true,
+            },
+        )
+        .and_then(|decoded| pack_value(Some(decoded.data)));
+        let (result, value) = match result {
+            Ok(value) => (0, value),
+            Err(e) => (e.code, Value::default()),
+        };
+        Ok((answer_id, json!({ "result": result, "value": value })))
+    }
+
+    fn decode_event(&self, args: &JsonValue) -> InterfaceResult {
+        let answer_id = decode_answer_id(args)?;
+        let result = decode_message(
+            self.ton.clone(),
+            ParamsOfDecodeMessage {
+                abi: Abi::Json(get_arg(args, "abi")?),
+                message: get_arg(args, "message")?,
+                ..Default::default()
+            },
+        )
+        .and_then(|decoded| match decoded.body_type {
+            MessageBodyType::Event => Ok((decoded.name, pack_value(decoded.value)?)),
+            _ => Err(Error::invalid_msg("message is not an event")),
+        });
+        let (result, name, value) = match result {
+            Ok((name, value)) => (0, name, value),
+            Err(e) => (e.code, String::new(), Value::default()),
+        };
+        Ok((answer_id, json!({ "result": result, "name": name, "value": value })))
+    }
+}
+
+fn parse_params(params: &str) -> ClientResult<Option<JsonValue>> {
+    if params.trim().is_empty() {
+        return Ok(None);
+    }
+    serde_json::from_str(params).map(Some).map_err(Error::invalid_json_params)
+}
+
+/// Returns `None` if function name is empty.
+fn call_set(function: &str, input: Option<JsonValue>) -> Option<CallSet> {
+    if function.is_empty() {
+        return None;
+    }
+    match input {
+        Some(input) => CallSet::some_with_function_and_input(function, input),
+        None => CallSet::some_with_function(function),
+    }
+}
+
+fn pack_value(value: Option<JsonValue>) -> ClientResult<Value> {
+    pack(value.unwrap_or_else(|| json!({})))
+        .ok_or_else(|| Error::execute_failed("failed to pack decoded value"))
+}
+
+fn body_type_code(body_type: &MessageBodyType) -> u8 {
+    match body_type {
+        MessageBodyType::Input => 0,
+        MessageBodyType::Output => 1,
+        MessageBodyType::InternalOutput => 2,
+        MessageBodyType::Event => 3,
+    }
+}
+
+#[async_trait::async_trait]
+impl DebotInterface for AbiInterface {
+    fn get_id(&self) -> String {
+        ABI_ID.to_string()
+    }
+
+    fn get_abi(&self) -> Abi {
+        Abi::Json(ABI.to_owned())
+    }
+
+    async fn call(&self, func: &str, args: &JsonValue) -> InterfaceResult {
+        match func {
+            "encodeMessageBody" => self.encode_message_body(args).await,
+            "decodeMessageBody" => self.decode_message_body(args),
+            "encodeInternalMessage" => self.encode_internal_message(args),
+            "decodeAccountData" => self.decode_account_data(args),
+            "decodeEvent" => self.decode_event(args),
+            _ => Err(format!("function \"{func}\" is not implemented")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::executor::block_on;
+
+    const TEST_ABI: &str = r#"{
+        "ABI version": 2,
+        "version": "2.2",
+        "header": [],
+        "functions": [
+            {
+                "name": "store",
+                "inputs": [
+                    {"name":"value","type":"uint32"},
+                    {"name":"text","type":"string"}
+                ],
+                "outputs": []
+            }
+        ],
+        "events": [],
+        "data": [],
+        "fields": []
+    }"#;
+
+    fn interface() -> AbiInterface {
+        AbiInterface::new(Arc::new(ClientContext::new(ClientConfig::default()).unwrap()))
+    }
+
+    fn encode_body(iface: &AbiInterface, function: &str, params: &str) -> JsonValue {
+        let args = json!({
+            "answerId": "1",
+            "abi": TEST_ABI,
+            "function": function,
+            "paramsJson": params,
+            "isInternal": true,
+        });
+        block_on(iface.call("encodeMessageBody", &args)).unwrap().1
+    }
+
+    #[test]
+    fn test_body_round_trip() {
+        let iface = interface();
+        let params = json!({ "value": "5", "text": "hello" });
+        let encoded = encode_body(&iface, "store", &params.to_string());
+        assert_eq!(encoded["result"], json!(0));
+
+        let args = json!({
+            "answerId": "2",
+            "abi": TEST_ABI,
+            "body": encoded["body"],
+            "isInternal": true,
+        });
+        let (answer_id, decoded) = block_on(iface.call("decodeMessageBody", &args)).unwrap();
+        assert_eq!(answer_id, 2);
+        assert_eq!(decoded["result"], json!(0));
+        assert_eq!(decoded["bodyType"], json!(0));
+        assert_eq!(decoded["name"], json!("store"));
+        assert_eq!(decoded["value"], json!(pack(params).unwrap()));
+    }
+
+    #[test]
+    fn test_empty_function() {
+        let iface = interface();
+        let encoded = encode_body(&iface, "", "");
+        assert_ne!(encoded["result"], json!(0));
+        assert_eq!(encoded["body"], json!(EMPTY_CELL));
+
+        let args = json!({
+            "answerId": "1",
+            "abi": TEST_ABI,
+            "dest": "0:1111111111111111111111111111111111111111111111111111111111111111",
+            "function": "",
+            "paramsJson": "",
+            "value": "1000000000",
+            "bounce": false,
+        });
+        let (_, encoded) = block_on(iface.call("encodeInternalMessage", &args)).unwrap();
+        assert_eq!(encoded["result"], json!(0));
+        assert_ne!(encoded["message"], json!(EMPTY_CELL));
+    }
+}
@@ -0,0 +1,115 @@
+use super::dinterface::{
+    decode_answer_id, get_arg, DebotInterface, InterfaceResult, MAX_INPUT_ATTEMPTS,
+};
+use crate::browser::{BrowserRef, LogLevel};
+use crate::dengine::load_ton_address;
+use crate::sdk_prelude::*;
+use serde_json::{json, Value};
+
+const ABI: &str = r#"
+{
+	"ABI version": 2,
+	"version": "2.2",
+	"header": ["time"],
+	"functions": [
+		{
+			"name": "get",
+			"inputs": [
+				{"name":"answerId","type":"uint32"},
+				{"name":"prompt","type":"string"}
+			],
+			"outputs": [
+				{"name":"value","type":"address"}
+			]
+		},
+		{
+			"name": "select",
+			"inputs": [
+				{"name":"answerId","type":"uint32"}
+			],
+			"outputs": [
+				{"name":"value","type":"address"}
+			]
+		},
+		{
+			"name": "constructor",
+			"inputs": [
+			],
+			"outputs": [
+			]
+		}
+	],
+	"data": [
+	],
+	"events": [
+	],
+	"fields": [
+		{"name":"_pubkey","type":"uint256"},
+		{"name":"_timestamp","type":"uint64"},
+		{"name":"_constructorFlag","type":"bool"}
+	]
+}
+"#;
+
+const ADDRESS_INPUT_ID: &str = "d7ed1bd8e6230871116f4522e58df0a93c5520c56f4ade23ef3d8919a984653b";
+
+pub struct AddressInputInterface {
+    browser: BrowserRef,
+}
+
+impl AddressInputInterface {
+    pub fn new(browser: BrowserRef) -> Self {
+        Self { browser }
+    }
+
+    async fn get(&self, args: &Value) -> InterfaceResult {
+        let answer_id = decode_answer_id(args)?;
+        let prompt = get_arg(args, "prompt")?;
+        let mut attempt = 1;
+        loop {
+            let value = self
+                .browser
+                .input_address(prompt.clone())
+                .await
+                .map_err(|e| format!("{e}"))?;
+            match load_ton_address(&value) {
+                Ok(value) => return Ok((answer_id, json!({ "value": value }))),
+                Err(e) if attempt < MAX_INPUT_ATTEMPTS => {
+                    self.browser.log(LogLevel::User, format!("{e}. Try again."));
+                    attempt += 1;
+                }
+                Err(e) => return Err(e),
+            }
+        }
+    }
+
+    async fn select(&self, args: &Value) -> InterfaceResult {
+        let answer_id = decode_answer_id(args)?;
+        let value = self
+            .browser
+            .select_wallet()
+            .await
+            .map_err(|e| format!("{e}"))?;
+        let value = load_ton_address(&value)?;
+        Ok((answer_id, json!({ "value": value })))
+    }
+}
+
+#[async_trait::async_trait]
+impl DebotInterface for AddressInputInterface {
+    fn get_id(&self) -> String {
+        ADDRESS_INPUT_ID.to_string()
+    }
+
+    fn get_abi(&self) -> Abi {
+        Abi::Json(ABI.to_owned())
+    }
+
+    async fn call(&self, func: &str, args: &Value) -> InterfaceResult {
+        match func {
+            "get" => self.get(args).await,
+            "select" => self.select(args).await,
+            _ => Err(format!("function \"{func}\" is not implemented")),
+        }
+    }
+}
@@ -0,0 +1,339 @@
+use super::dinterface::{
+    decode_answer_id, get_arg, get_bool_arg, get_num_arg, DebotInterface, InterfaceResult,
+    ZERO_ADDRESS,
+};
+use crate::dengine::load_ton_address;
+use crate::sdk_prelude::*;
+use num_traits::Zero;
+use serde_json::{json, Value};
+use ton_client::utils::{convert_address, AddressStringFormat, ParamsOfConvertAddress};
+
+const ABI: &str = r#"
+{
+	"ABI version": 2,
+	"version": "2.2",
+	"header": ["time"],
+	"functions": [
+		{
+			"name": "validate",
+			"inputs": [
+				{"name":"answerId","type":"uint32"},
+				{"name":"value","type":"string"}
+			],
+			"outputs": [
+				{"name":"valid","type":"bool"},
+				{"name":"addr","type":"address"}
+			]
+		},
+		{
+			"name": "toUserFriendly",
+			"inputs": [
+				{"name":"answerId","type":"uint32"},
+				{"name":"addr","type":"address"},
+				{"name":"bounceable","type":"bool"},
+				{"name":"testnet","type":"bool"},
+				{"name":"urlSafe","type":"bool"}
+			],
+			"outputs": [
+				{"name":"value","type":"string"}
+			]
+		},
+		{
+			"name": "convert",
+			"inputs": [
+				{"name":"answerId","type":"uint32"},
+				{"name":"value","type":"string"},
+				{"name":"format","type":"uint8"},
+				{"name":"bounceable","type":"bool"},
+				{"name":"testnet","type":"bool"},
+				{"name":"urlSafe","type":"bool"}
+			],
+			"outputs": [
+				{"name":"valid","type":"bool"},
+				{"name":"value","type":"string"}
+			]
+		},
+		{
+			"name": "calcAddress",
+			"inputs": [
+				{"name":"answerId","type":"uint32"},
+				{"name":"tvc","type":"cell"},
+				{"name":"abi","type":"string"},
+				{"name":"initialData","type":"string"},
+				{"name":"pubkey","type":"uint256"},
+				{"name":"wc","type":"int8"}
+			],
+			"outputs": [
+				{"name":"result","type":"uint32"},
+				{"name":"addr","type":"address"}
+			]
+		},
+		{
+			"name": "constructor",
+			"inputs": [
+			],
+			"outputs": [
+			]
+		}
+	],
+	"data": [
+	],
+	"events": [
+	],
+	"fields": [
+		{"name":"_pubkey","type":"uint256"},
+		{"name":"_timestamp","type":"uint64"},
+		{"name":"_constructorFlag","type":"bool"}
+	]
+}
+"#;
+
+const ADDRESS_ID: &str = "56ef8f20955f256423d328f65b62106005252551db25d660031887e421cb4684";
+
+const FORMAT_RAW: u8 = 0;
+const FORMAT_USER_FRIENDLY: u8 = 1;
+const FORMAT_ACCOUNT_ID: u8 = 2;
+
+/// Address interface. Converts and validates addresses, calculates deploy addresses.
+///
+/// Formats used by `convert`: 0 - raw (`wc:hex`), 1 - user-friendly (base64), 2 - account id.
+pub struct AddressInterface {
+    ton: TonClient,
+}
+
+impl AddressInterface {
+    pub fn new(ton: TonClient) -> Self {
+        Self { ton }
+    }
+
+    fn validate(&self, args: &Value) -> InterfaceResult {
+        let answer_id = decode_answer_id(args)?;
+        let value = get_arg(args, "value")?;
+        let (valid, addr) = match load_ton_address(&value) {
+            Ok(addr) => (true, addr),
+            Err(_) => (false, ZERO_ADDRESS.to_owned()),
+        };
+        Ok((answer_id, json!({ "valid": valid, "addr": addr })))
+    }
+
+    fn to_user_friendly(&self, args: &Value) -> InterfaceResult {
+        let answer_id = decode_answer_id(args)?;
+        let addr = load_ton_address(&get_arg(args, "addr")?)?;
+        let format = base64_format(args)?;
+        let value = self.convert_address(&addr, format)?;
+        Ok((answer_id, json!({ "value": value })))
+    }
+
+    fn convert(&self, args: &Value) -> InterfaceResult {
+        let answer_id = decode_answer_id(args)?;
+        let value = get_arg(args, "value")?;
+        let format = match get_num_arg::<u8>(args, "format")? {
+            FORMAT_RAW => AddressStringFormat::Hex,
+            FORMAT_USER_FRIENDLY => base64_format(args)?,
+            FORMAT_ACCOUNT_ID => AddressStringFormat::AccountId,
+            format => return Err(format!("unsupported address format {format}")),
+        };
+        let (valid, value) = match load_ton_address(&value) {
+            Ok(addr) => (true, self.convert_address(&addr, format)?),
+            Err(_) => (false, String::new()),
+        };
+        Ok((answer_id, json!({ "valid": valid, "value": value })))
+    }
+
+    async fn calc_address(&self, args: &Value) -> InterfaceResult {
+        let answer_id = decode_answer_id(args)?;
+        let tvc = get_arg(args, "tvc")?;
+        let abi = get_arg(args, "abi")?;
+        let initial_data = get_arg(args, "initialData")?;
+        let initial_data: Option<Value> = if initial_data.trim().is_empty() {
+            None
+        } else {
+            Some(
+                serde_json::from_str(&initial_data)
+                    .map_err(|e| format!("invalid initial data JSON: {e}"))?,
+            )
+        };
+        let pubkey = decode_abi_bigint(&get_arg(args, "pubkey")?).map_err(|e| e.to_string())?;
+        let pubkey = if pubkey.is_zero() {
+            None
+        } else {
+            Some(format!("{pubkey:064x}"))
+        };
+        let wc = get_num_arg::<i8>(args, "wc")?;
+
+        let result = encode_message(
+            self.ton.clone(),
+            ParamsOfEncodeMessage {
+                abi: Abi::Json(abi),
+                deploy_set: Some(DeploySet {
+                    tvc: Some(tvc),
+                    workchain_id: Some(wc as i32),
+                    initial_data,
+                    initial_pubkey: pubkey.clone(),
+                    ..Default::default()
+                }),
+                signer: pubkey
+                    .map(|public_key| Signer::External { public_key })
+                    .unwrap_or(Signer::None),
+                ..Default::default()
+            },
+        )
+        .await;
+        let (result, addr) = match result {
+            Ok(msg) => (0, msg.address),
+            Err(e) => (e.code, ZERO_ADDRESS.to_owned()),
+        };
+        Ok((answer_id, json!({ "result": result, "addr": addr })))
+    }
+
+    fn convert_address(
+        &self,
+        address: &str,
+        format: AddressStringFormat,
+    ) -> Result<String, String> {
+        convert_address(
+            self.ton.clone(),
+            ParamsOfConvertAddress {
+                address: address.to_owned(),
+                output_format: format,
+            },
+        )
+        .map(|r| r.address)
+        .map_err(|e| format!("failed to convert address \"{address}\": {e}"))
+    }
+}
+
+fn base64_format(args: &Value) -> Result<AddressStringFormat, String> {
+    Ok(AddressStringFormat::Base64 {
+        url: get_bool_arg(args, "urlSafe")?,
+        test: get_bool_arg(args, "testnet")?,
+        bounce: get_bool_arg(args, "bounceable")?,
+    })
+}
+
+#[async_trait::async_trait]
+impl DebotInterface for AddressInterface {
+    fn get_id(&self) -> String {
+        ADDRESS_ID.to_string()
+    }
+
+    fn get_abi(&self) -> Abi {
+        Abi::Json(ABI.to_owned())
+    }
+
+    async fn call(&self, func: &str, args: &Value) -> InterfaceResult {
+        match func {
+            "validate" => self.validate(args),
+            "toUserFriendly" => self.to_user_friendly(args),
+            "convert" => self.convert(args),
+            "calcAddress" => self.calc_address(args).await,
+            _ => Err(format!("function \"{func}\" is not implemented")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const RAW: &str = "0:1111111111111111111111111111111111111111111111111111111111111111";
+
+    fn address() -> AddressInterface {
+        AddressInterface::new(Arc::new(ClientContext::new(ClientConfig::default()).unwrap()))
+    }
+
+    fn user_friendly(iface: &AddressInterface, bounce: bool, url: bool) -> String {
+        let format = AddressStringFormat::Base64 {
+            url,
+            test: false,
+            bounce,
+        };
+        iface.convert_address(RAW, format).unwrap()
+    }
+
+    /// Address in all accepted forms: raw, bounceable, non-bounceable and url-safe.
+    fn valid_inputs(iface: &AddressInterface) -> Vec<String> {
+        vec![
+            RAW.to_owned(),
+            format!(" {RAW} "),
+            user_friendly(iface, true, false),
+            user_friendly(iface, false, false),
+            user_friendly(iface, true, true),
+        ]
+    }
+
+    fn invalid_inputs(iface: &AddressInterface) -> Vec<String> {
+        let mut broken_crc = user_friendly(iface, true, false);
+        let last = if broken_crc.ends_with('A') { 'B' } else { 'A' };
+        broken_crc.pop();
+        broken_crc.push(last);
+        vec![
+            String::new(),
+            "hello".to_owned(),
+            "0:123".to_owned(),
+            format!("{RAW}0"),
+            broken_crc,
+        ]
+    }
+
+    #[test]
+    fn test_validate() {
+        let iface = address();
+        let validate = |value: &str| {
+            iface
+                .validate(&json!({ "answerId": "1", "value": value }))
+                .unwrap()
+                .1
+        };
+        for value in valid_inputs(&iface) {
+            assert_eq!(validate(&value), json!({ "valid": true, "addr": RAW }), "{value}");
+        }
+        for value in invalid_inputs(&iface) {
+            assert_eq!(
+                validate(&value),
+                json!({ "valid": false, "addr": ZERO_ADDRESS }),
+                "{value}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_convert() {
+        let iface = address();
+        let convert = |value: &str, format: u8| {
+            iface
+                .convert(&json!({
+                    "answerId": "1",
+                    "value": value,
+                    "format": format.to_string(),
+                    "bounceable": true,
+                    "testnet": false,
+                    "urlSafe": true,
+                }))
+                .unwrap()
+                .1
+        };
+        let url_safe = user_friendly(&iface, true, true);
+        for value in valid_inputs(&iface) {
+            assert_eq!(convert(&value, FORMAT_RAW), json!({ "valid": true, "value": RAW }));
+            assert_eq!(
+                convert(&value, FORMAT_USER_FRIENDLY),
+                json!({ "valid": true, "value": url_safe })
+            );
+        }
+        for value in invalid_inputs(&iface) {
+            assert_eq!(convert(&value, FORMAT_RAW), json!({ "valid": false, "value": "" }));
+        }
+        let to_user_friendly = |addr: &str| {
+            iface.to_user_friendly(&json!({
+                "answerId": "1",
+                "addr": addr,
+                "bounceable": true,
+                "testnet": false,
+                "urlSafe": true,
+            }))
+        };
+        assert_eq!(to_user_friendly(RAW).unwrap().1, json!({ "value": url_safe }));
+        assert!(to_user_friendly("0:123").is_err());
+    }
+}
@@ -0,0 +1,158 @@
+use super::dinterface::{
+    decode_answer_id, get_arg, get_num_arg, parse_decimal, DebotInterface, InterfaceResult,
+    MAX_INPUT_ATTEMPTS,
+};
+use crate::browser::{BrowserRef, LogLevel};
+use crate::sdk_prelude::*;
+use serde_json::{json, Value};
+
+const ABI: &str = r#"
+{
+	"ABI version": 2,
+	"version": "2.2",
+	"header": ["time"],
+	"functions": [
+		{
+			"name": "get",
+			"inputs": [
+				{"name":"answerId","type":"uint32"},
+				{"name":"prompt","type":"string"},
+				{"name":"decimals","type":"uint8"},
+				{"name":"min","type":"uint128"},
+				{"name":"max","type":"uint128"}
+			],
+			"outputs": [
+				{"name":"value","type":"uint128"}
+			]
+		},
+		{
+			"name": "constructor",
+			"inputs": [
+			],
+			"outputs": [
+			]
+		}
+	],
+	"data": [
+	],
+	"events": [
+	],
+	"fields": [
+		{"name":"_pubkey","type":"uint256"},
+		{"name":"_timestamp","type":"uint64"},
+		{"name":"_constructorFlag","type":"bool"}
+	]
+}
+"#;
+
+const AMOUNT_INPUT_ID: &str = "a1d347099e29c1624c8890619daf207bde18e92df5220a54bcc6d858309ece84";
+
+pub struct AmountInputInterface {
+    browser: BrowserRef,
+}
+
+impl AmountInputInterface {
+    pub fn new(browser: BrowserRef) -> Self {
+        Self { browser }
+    }
+
+    async fn get(&self, args: &Value) -> InterfaceResult {
+        let answer_id = decode_answer_id(args)?;
+        let prompt = get_arg(args, "prompt")?;
+        let decimals = get_num_arg::<u8>(args, "decimals")?;
+        let min = decode_abi_number::<u128>(&get_arg(args, "min")?)
+            .map_err(|e| format!("{e}"))?;
+        let max = decode_abi_number::<u128>(&get_arg(args, "max")?)
+            .map_err(|e| format!("{e}"))?;
+        if min > max {
+            return Err(format!("invalid range: min {min} is greater than max {max}"));
+        }
+        let mut attempt = 1;
+        loop {
+            let value = self
+                .browser
+                .input_amount(prompt.clone(), decimals, min.to_string(), max.to_string())
+                .await
+                .map_err(|e| format!("{e}"))?;
+            match check_amount(value.trim(), decimals, min, max) {
+                Ok(value) => return Ok((answer_id, json!({ "value": value.to_string() }))),
+                Err(e) if attempt < MAX_INPUT_ATTEMPTS => {
+                    self.browser.log(LogLevel::User, format!("{e}. Try again."));
+                    attempt += 1;
+                }
+                Err(e) => return Err(e),
+            }
+        }
+    }
+}
+
+fn check_amount(value: &str, decimals: u8, min: u128, max: u128) -> Result<u128, String> {
+    let amount = parse_decimal(value, decimals)?;
+    if amount < min || amount > max {
+        return Err(format!(
+            "amount \"{value}\" is out of range [{}, {}]",
+            format_decimal(min, decimals),
+            format_decimal(max, decimals),
+        ));
+    }
+    Ok(amount)
+}
+
+fn format_decimal(value: u128, decimals: u8) -> String {
+    let decimals = decimals as usize;
+    let digits = format!("{value:0>width$}", width = decimals + 1);
+    let (integer, fraction) = digits.split_at(digits.len() - decimals);
+    let fraction = fraction.trim_end_matches('0');
+    if fraction.is_empty() {
+        integer.to_owned()
+    } else {
+        format!("{integer}.{fraction}")
+    }
+}
+
+#[async_trait::async_trait]
+impl DebotInterface for AmountInputInterface {
+    fn get_id(&self) -> String {
+        AMOUNT_INPUT_ID.to_string()
+    }
+
+    fn get_abi(&self) -> Abi {
+        Abi::Json(ABI.to_owned())
+    }
+
+    async fn call(&self, func: &str, args: &Value) -> InterfaceResult {
+        match func {
+            "get" => self.get(args).await,
+            _ => Err(format!("function \"{func}\" is not implemented")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{check_amount, format_decimal};
+
+    #[test]
+    fn test_format_decimal() {
+        assert_eq!(format_decimal(1_500_000_000, 9), "1.5");
+        assert_eq!(format_decimal(1_000_000_000, 9), "1");
+        assert_eq!(format_decimal(5, 3), "0.005");
+        assert_eq!(format_decimal(0, 9), "0");
+        assert_eq!(format_decimal(120, 0), "120");
+        assert_eq!(format_decimal(u128::MAX, 0), u128::MAX.to_string());
+        assert_eq!(
+            format_decimal(u128::MAX, 38),
+            "3.40282366920938463463374607431768211455"
+        );
+    }
+
+    #[test]
+    fn test_check_amount() {
+        assert_eq!(check_amount("1.5", 9, 0, 2_000_000_000), Ok(1_500_000_000));
+        assert_eq!(
+            check_amount("3", 9, 0, 2_000_000_000),
+            Err("amount \"3\" is out of range [0, 2]".to_owned())
+        );
+        assert!(check_amount("0.5", 1, 10, 20).is_err());
+    }
+}
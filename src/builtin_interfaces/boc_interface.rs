@@ -0,0 +1,479 @@
+use super::dinterface::{decode_answer_id, get_arg, DebotInterface, InterfaceResult, ZERO_ADDRESS};
+use crate::dengine::EMPTY_CELL;
+use crate::sdk_prelude::*;
+use serde_json::{json, Value};
+use ton_client::boc::{
+    decode_state_init, encode_boc, encode_state_init, get_boc_depth, get_code_from_tvc,
+    get_code_salt, get_compiler_version, parse_transaction, set_code_salt, BuilderOp,
+    ParamsOfDecodeStateInit, ParamsOfEncodeBoc, ParamsOfEncodeStateInit, ParamsOfGetBocDepth,
+    ParamsOfGetCodeFromTvc, ParamsOfGetCodeSalt, ParamsOfGetCompilerVersion, ParamsOfSetCodeSalt,
+};
+
+const ABI: &str = r#"
+{
+	"ABI version": 2,
+	"version": "2.2",
+	"header": ["time"],
+	"functions": [
+		{
+			"name": "getBocHash",
+			"inputs": [
+				{"name":"answerId","type":"uint32"},
+				{"name":"boc","type":"cell"}
+			],
+			"outputs": [
+				{"name":"hash","type":"uint256"}
+			]
+		},
+		{
+			"name": "getBocDepth",
+			"inputs": [
+				{"name":"answerId","type":"uint32"},
+				{"name":"boc","type":"cell"}
+			],
+			"outputs": [
+				{"name":"depth","type":"uint32"}
+			]
+		},
+		{
+			"name": "getCodeFromTvc",
+			"inputs": [
+				{"name":"answerId","type":"uint32"},
+				{"name":"tvc","type":"cell"}
+			],
+			"outputs": [
+				{"name":"code","type":"cell"}
+			]
+		},
+		{
+			"name": "getCompilerVersion",
+			"inputs": [
+				{"name":"answerId","type":"uint32"},
+				{"name":"code","type":"cell"}
+			],
+			"outputs": [
+				{"name":"version","type":"string"}
+			]
+		},
+		{
+			"name": "encodeBoc",
+			"inputs": [
+				{"name":"answerId","type":"uint32"},
+				{"name":"builder","type":"string"}
+			],
+			"outputs": [
+				{"name":"boc","type":"cell"}
+			]
+		},
+		{
+			"name": "parseMessage",
+			"inputs": [
+				{"name":"answerId","type":"uint32"},
+				{"name":"boc","type":"cell"}
+			],
+			"outputs": [
+				{"components":[{"name":"id","type":"uint256"},{"name":"msgType","type":"uint8"},{"name":"src","type":"string"},{"name":"dst","type":"string"},{"name":"value","type":"uint128"},{"name":"bounce","type":"bool"},{"name":"bounced","type":"bool"},{"name":"createdLt","type":"uint64"},{"name":"createdAt","type":"uint32"},{"name":"body","type":"cell"}],"name":"message","type":"tuple"}
+			]
+		},
+		{
+			"name": "parseTransaction",
+			"inputs": [
+				{"name":"answerId","type":"uint32"},
+				{"name":"boc","type":"cell"}
+			],
+			"outputs": [
+				{"components":[{"name":"id","type":"uint256"},{"name":"account","type":"address"},{"name":"lt","type":"uint64"},{"name":"now","type":"uint32"},{"name":"trType","type":"uint8"},{"name":"aborted","type":"bool"},{"name":"totalFees","type":"uint128"},{"name":"inMsg","type":"uint256"},{"name":"outMsgs","type":"uint256[]"},{"name":"exitCode","type":"int32"},{"name":"success","type":"bool"}],"name":"transaction","type":"tuple"}
+			]
+		},
+		{
+			"name": "setCodeSalt",
+			"inputs": [
+				{"name":"answerId","type":"uint32"},
+				{"name":"code","type":"cell"},
+				{"name":"salt","type":"cell"}
+			],
+			"outputs": [
+				{"name":"code","type":"cell"}
+			]
+		},
+		{
+			"name": "getCodeSalt",
+			"inputs": [
+				{"name":"answerId","type":"uint32"},
+				{"name":"code","type":"cell"}
+			],
+			"outputs": [
+				{"name":"hasSalt","type":"bool"},
+				{"name":"salt","type":"cell"}
+			]
+		},
+		{
+			"name": "encodeStateInit",
+			"inputs": [
+				{"name":"answerId","type":"uint32"},
+				{"name":"code","type":"cell"},
+				{"name":"data","type":"cell"}
+			],
+			"outputs": [
+				{"name":"stateInit","type":"cell"}
+			]
+		},
+		{
+			"name": "decodeStateInit",
+			"inputs": [
+				{"name":"answerId","type":"uint32"},
+				{"name":"stateInit","type":"cell"}
+			],
+			"outputs": [
+				{"name":"code","type":"cell"},
+				{"name":"codeHash","type":"uint256"},
+				{"name":"data","type":"cell"},
+				{"name":"dataHash","type":"uint256"},
+				{"name":"compilerVersion","type":"string"}
+			]
+		},
+		{
+			"name": "constructor",
+			"inputs": [
+			],
+			"outputs": [
+			]
+		}
+	],
+	"data": [
+	],
+	"events": [
+	],
+	"fields": [
+		{"name":"_pubkey","type":"uint256"},
+		{"name":"_timestamp","type":"uint64"},
+		{"name":"_constructorFlag","type":"bool"}
+	]
+}
+"#;
+
+const BOC_ID: &str = "b15acd47fd8bc6b867525b0653d42f0a4a5ba988adaeab74ab33696d4e0f8594";
+
+/// BOC interface. Wraps cell and BOC functions of SDK.
+///
+/// `encodeBoc` accepts builder as JSON array of SDK `BuilderOp` objects.
+pub struct BocInterface {
+    ton: TonClient,
+}
+
+impl BocInterface {
+    pub fn new(ton: TonClient) -> Self {
+        Self { ton }
+    }
+
+    fn get_boc_hash(&self, args: &Value) -> InterfaceResult {
+        let answer_id = decode_answer_id(args)?;
+        let boc = get_arg(args, "boc")?;
+        let result =
+            get_boc_hash(self.ton.clone(), ParamsOfGetBocHash { boc }).map_err(|e| format!("{e}"))?;
+        Ok((answer_id, json!({ "hash": format!("0x{}", result.hash) })))
+    }
+
+    fn get_boc_depth(&self, args: &Value) -> InterfaceResult {
+        let answer_id = decode_answer_id(args)?;
+        let boc = get_arg(args, "boc")?;
+        let result = get_boc_depth(self.ton.clone(), ParamsOfGetBocDepth { boc })
+            .map_err(|e| format!("{e}"))?;
+        Ok((answer_id, json!({ "depth": result.depth })))
+    }
+
+    fn get_code_from_tvc(&self, args: &Value) -> InterfaceResult {
+        let answer_id = decode_answer_id(args)?;
+        let tvc = get_arg(args, "tvc")?;
+        let result = get_code_from_tvc(self.ton.clone(), ParamsOfGetCodeFromTvc { tvc })
+            .map_err(|e| format!("{e}"))?;
+        Ok((answer_id, json!({ "code": result.code })))
+    }
+
+    fn get_compiler_version(&self, args: &Value) -> InterfaceResult {
+        let answer_id = decode_answer_id(args)?;
+        let code = get_arg(args, "code")?;
+        let result = get_compiler_version(self.ton.clone(), ParamsOfGetCompilerVersion { code })
+            .map_err(|e| format!("{e}"))?;
+        Ok((answer_id, json!({ "version": result.version.unwrap_or_default() })))
+    }
+
+    fn encode_boc(&self, args: &Value) -> InterfaceResult {
+        let answer_id = decode_answer_id(args)?;
+        let builder: Vec<BuilderOp> = serde_json::from_str(&get_arg(args, "builder")?)
+            .map_err(|e| format!("invalid builder: {e}"))?;
+        let result = encode_boc(
+            self.ton.clone(),
+            ParamsOfEncodeBoc {
+                builder,
+                boc_cache: None,
+            },
+        )
+        .map_err(|e| format!("{e}"))?;
+        Ok((answer_id, json!({ "boc": result.boc })))
+    }
+
+    fn parse_message(&self, args: &Value) -> InterfaceResult {
+        let answer_id = decode_answer_id(args)?;
+        let boc = get_arg(args, "boc")?;
+        let msg = parse_message(self.ton.clone(), ParamsOfParse { boc })
+            .map_err(|e| format!("{e}"))?
+            .parsed;
+        Ok((answer_id, json!({ "message": message_tuple(&msg) })))
+    }
+
+    fn parse_transaction(&self, args: &Value) -> InterfaceResult {
+        let answer_id = decode_answer_id(args)?;
+        let boc = get_arg(args, "boc")?;
+        let tr = parse_transaction(self.ton.clone(), ParamsOfParse { boc })
+            .map_err(|e| format!("{e}"))?
+            .parsed;
+        Ok((answer_id, json!({ "transaction": transaction_tuple(&tr) })))
+    }
+
+    fn set_code_salt(&self, args: &Value) -> InterfaceResult {
+        let answer_id = decode_answer_id(args)?;
+        let result = set_code_salt(
+            self.ton.clone(),
+            ParamsOfSetCodeSalt {
+                code: get_arg(args, "code")?,
+                salt: get_arg(args, "salt")?,
+                boc_cache: None,
+            },
+        )
+        .map_err(|e| format!("{e}"))?;
+        Ok((answer_id, json!({ "code": result.code })))
+    }
+
+    fn get_code_salt(&self, args: &Value) -> InterfaceResult {
+        let answer_id = decode_answer_id(args)?;
+        let result = get_code_salt(
+            self.ton.clone(),
+            ParamsOfGetCodeSalt {
+                code: get_arg(args, "code")?,
+                boc_cache: None,
+            },
+        )
+        .map_err(|e| format!("{e}"))?;
+        Ok((
+            answer_id,
+            json!({
+                "hasSalt": result.salt.is_some(),
+                "salt": result.salt.unwrap_or_else(|| EMPTY_CELL.to_owned()),
+            }),
+        ))
+    }
+
+    fn encode_state_init(&self, args: &Value) -> InterfaceResult {
+        let answer_id = decode_answer_id(args)?;
+        let result = encode_state_init(
+            self.ton.clone(),
+            ParamsOfEncodeStateInit {
+                code: Some(get_arg(args, "code")?),
+                data: Some(get_arg(args, "data")?),
+                ..Default::default()
+            },
+        )
+        .map_err(|e| format!("{e}"))?;
+        Ok((answer_id, json!({ "stateInit": result.state_init })))
+    }
+
+    fn decode_state_init(&self, args: &Value) -> InterfaceResult {
+        let answer_id = decode_answer_id(args)?;
+        let result = decode_state_init(
+            self.ton.clone(),
+            ParamsOfDecodeStateInit {
+                state_init: get_arg(args, "stateInit")?,
+                boc_cache: None,
+            },
+        )
+        .map_err(|e| format!("{e}"))?;
+        Ok((
+            answer_id,
+            json!({
+                "code": result.code.unwrap_or_else(|| EMPTY_CELL.to_owned()),
+                "codeHash": format!("0x{}", result.code_hash.unwrap_or_else(|| "0".to_owned())),
+                "data": result.data.unwrap_or_else(|| EMPTY_CELL.to_owned()),
+                "dataHash": format!("0x{}", result.data_hash.unwrap_or_else(|| "0".to_owned())),
+                "compilerVersion": result.compiler_version.unwrap_or_default(),
+            }),
+        ))
+    }
+}
+
+/// Converts message parsed by SDK to `message` tuple of `parseMessage`.
+fn message_tuple(msg: &Value) -> Value {
+    json!({
+        "id": hex_uint(&msg["id"]),
+        "msgType": msg["msg_type"].as_u64().unwrap_or_default(),
+        "src": msg["src"].as_str().unwrap_or_default(),
+        "dst": msg["dst"].as_str().unwrap_or_default(),
+        "value": num_or_zero(&msg["value"]),
+        "bounce": msg["bounce"].as_bool().unwrap_or_default(),
+        "bounced": msg["bounced"].as_bool().unwrap_or_default(),
+        "createdLt": num_or_zero(&msg["created_lt"]),
+        "createdAt": msg["created_at"].as_u64().unwrap_or_default(),
+        "body": msg["body"].as_str().unwrap_or(EMPTY_CELL),
+    })
+}
+
+/// Converts transaction parsed by SDK to `transaction` tuple of `parseTransaction`.
+fn transaction_tuple(tr: &Value) -> Value {
+    let out_msgs: Vec<String> = tr["out_msgs"]
+        .as_array()
+        .map(|ids| ids.iter().map(hex_uint).collect())
+        .unwrap_or_default();
+    json!({
+        "id": hex_uint(&tr["id"]),
+        "account": tr["account_addr"]
+            .as_str()
+            .filter(|s| !s.is_empty())
+            .unwrap_or(ZERO_ADDRESS),
+        "lt": num_or_zero(&tr["lt"]),
+        "now": tr["now"].as_u64().unwrap_or_default(),
+        "trType": tr["tr_type"].as_u64().unwrap_or_default(),
+        "aborted": tr["aborted"].as_bool().unwrap_or_default(),
+        "totalFees": num_or_zero(&tr["total_fees"]),
+        "inMsg": hex_uint(&tr["in_msg"]),
+        "outMsgs": out_msgs,
+        "exitCode": tr["compute"]["exit_code"].as_i64().unwrap_or_default(),
+        "success": tr["compute"]["success"].as_bool().unwrap_or_default(),
+    })
+}
+
+/// Converts hash (hex string without prefix) to uint256 ABI value.
+fn hex_uint(value: &Value) -> String {
+    format!("0x{}", value.as_str().filter(|s| !s.is_empty()).unwrap_or("0"))
+}
+
+/// Returns big number from parsed BOC as is (hex string or number), or zero if it is absent.
+fn num_or_zero(value: &Value) -> Value {
+    if value.is_null() {
+        json!(0)
+    } else {
+        value.clone()
+    }
+}
+
+#[async_trait::async_trait]
+impl DebotInterface for BocInterface {
+    fn get_id(&self) -> String {
+        BOC_ID.to_string()
+    }
+
+    fn get_abi(&self) -> Abi {
+        Abi::Json(ABI.to_owned())
+    }
+
+    async fn call(&self, func: &str, args: &Value) -> InterfaceResult {
+        match func {
+            "getBocHash" => self.get_boc_hash(args),
+            "getBocDepth" => self.get_boc_depth(args),
+            "getCodeFromTvc" => self.get_code_from_tvc(args),
+            "getCompilerVersion" => self.get_compiler_version(args),
+            "encodeBoc" => self.encode_boc(args),
+            "parseMessage" => self.parse_message(args),
+            "parseTransaction" => self.parse_transaction(args),
+            "setCodeSalt" => self.set_code_salt(args),
+            "getCodeSalt" => self.get_code_salt(args),
+            "encodeStateInit" => self.encode_state_init(args),
+            "decodeStateInit" => self.decode_state_init(args),
+            _ => Err(format!("function \"{func}\" is not implemented")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ADDR: &str = "0:1111111111111111111111111111111111111111111111111111111111111111";
+
+    #[test]
+    fn test_hex_uint() {
+        assert_eq!(hex_uint(&json!("ab01")), "0xab01");
+        assert_eq!(hex_uint(&json!("")), "0x0");
+        assert_eq!(hex_uint(&Value::Null), "0x0");
+        assert_eq!(hex_uint(&json!(5)), "0x0");
+    }
+
+    #[test]
+    fn test_num_or_zero() {
+        assert_eq!(num_or_zero(&json!("0x3b9aca00")), json!("0x3b9aca00"));
+        assert_eq!(num_or_zero(&json!(100)), json!(100));
+        assert_eq!(num_or_zero(&Value::Null), json!(0));
+    }
+
+    #[test]
+    fn test_message_tuple() {
+        let msg = json!({
+            "id": "aa",
+            "msg_type": 0,
+            "src": ADDR,
+            "dst": ADDR,
+            "value": "0x3b9aca00",
+            "bounce": true,
+            "bounced": false,
+            "created_lt": "0x1a",
+            "created_at": 1650000000,
+            "body": "te6ccgEBAQEAAgAAAA==",
+        });
+        assert_eq!(
+            message_tuple(&msg),
+            json!({
+                "id": "0xaa",
+                "msgType": 0,
+                "src": ADDR,
+                "dst": ADDR,
+                "value": "0x3b9aca00",
+                "bounce": true,
+                "bounced": false,
+                "createdLt": "0x1a",
+                "createdAt": 1650000000,
+                "body": "te6ccgEBAQEAAgAAAA==",
+            })
+        );
+        let empty = message_tuple(&json!({}));
+        assert_eq!(empty["id"], json!("0x0"));
+        assert_eq!(empty["value"], json!(0));
+        assert_eq!(empty["body"], json!(EMPTY_CELL));
+    }
+
+    #[test]
+    fn test_transaction_tuple() {
+        let tr = json!({
+            "id": "bb",
+            "account_addr": ADDR,
+            "lt": "0x10",
+            "now": 1650000000,
+            "tr_type": 0,
+            "aborted": false,
+            "total_fees": "0x64",
+            "in_msg": "cc",
+            "out_msgs": ["dd", "ee"],
+            "compute": { "exit_code": -14, "success": false },
+        });
+        assert_eq!(
+            transaction_tuple(&tr),
+            json!({
+                "id": "0xbb",
+                "account": ADDR,
+                "lt": "0x10",
+                "now": 1650000000,
+                "trType": 0,
+                "aborted": false,
+                "totalFees": "0x64",
+                "inMsg": "0xcc",
+                "outMsgs": ["0xdd", "0xee"],
+                "exitCode": -14,
+                "success": false,
+            })
+        );
+        // `account` is ABI address, so it can't be empty
+        let empty = transaction_tuple(&json!({ "account_addr": "" }));
+        assert_eq!(empty["account"], json!(ZERO_ADDRESS));
+        assert_eq!(empty["inMsg"], json!("0x0"));
+        assert_eq!(empty["outMsgs"], json!([]));
+        assert_eq!(empty["totalFees"], json!(0));
+    }
+}
@@ -0,0 +1,81 @@
+use super::dinterface::{decode_answer_id, get_arg, DebotInterface, InterfaceResult};
+use crate::browser::BrowserRef;
+use crate::sdk_prelude::*;
+use serde_json::{json, Value};
+
+const ABI: &str = r#"
+{
+	"ABI version": 2,
+	"version": "2.2",
+	"header": ["time"],
+	"functions": [
+		{
+			"name": "get",
+			"inputs": [
+				{"name":"answerId","type":"uint32"},
+				{"name":"prompt","type":"string"}
+			],
+			"outputs": [
+				{"name":"value","type":"bool"}
+			]
+		},
+		{
+			"name": "constructor",
+			"inputs": [
+			],
+			"outputs": [
+			]
+		}
+	],
+	"data": [
+	],
+	"events": [
+	],
+	"fields": [
+		{"name":"_pubkey","type":"uint256"},
+		{"name":"_timestamp","type":"uint64"},
+		{"name":"_constructorFlag","type":"bool"}
+	]
+}
+"#;
+
+const CONFIRM_INPUT_ID: &str = "16653eaf34c921467120f2685d425ff963db5cbb5aa676a62a2e33bfc3f6828a";
+
+pub struct ConfirmInputInterface {
+    browser: BrowserRef,
+}
+
+impl ConfirmInputInterface {
+    pub fn new(browser: BrowserRef) -> Self {
+        Self { browser }
+    }
+
+    async fn get(&self, args: &Value) -> InterfaceResult {
+        let answer_id = decode_answer_id(args)?;
+        let prompt = get_arg(args, "prompt")?;
+        let value = self
+            .browser
+            .confirm(prompt)
+            .await
+            .map_err(|e| format!("{e}"))?;
+        Ok((answer_id, json!({ "value": value })))
+    }
+}
+
+#[async_trait::async_trait]
+impl DebotInterface for ConfirmInputInterface {
+    fn get_id(&self) -> String {
+        CONFIRM_INPUT_ID.to_string()
+    }
+
+    fn get_abi(&self) -> Abi {
+        Abi::Json(ABI.to_owned())
+    }
+
+    async fn call(&self, func: &str, args: &Value) -> InterfaceResult {
+        match func {
+            "get" => self.get(args).await,
+            _ => Err(format!("function \"{func}\" is not implemented")),
+        }
+    }
+}
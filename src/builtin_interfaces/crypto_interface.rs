@@ -0,0 +1,444 @@
+use super::dinterface::{decode_answer_id, get_arg, get_num_arg, DebotInterface, InterfaceResult};
+use crate::sdk_prelude::*;
+use serde_json::{json, Value};
+use ton_client::crypto::{
+    nacl_secret_box, nacl_secret_box_open, nacl_sign_detached, nacl_sign_detached_verify, scrypt,
+    sha256, sha512, verify_signature, ParamsOfHash, ParamsOfNaclSecretBox,
+    ParamsOfNaclSecretBoxOpen, ParamsOfNaclSign, ParamsOfNaclSignDetachedVerify, ParamsOfScrypt,
+    ParamsOfVerifySignature,
+};
+
+const ABI: &str = r#"
+{
+	"ABI version": 2,
+	"version": "2.2",
+	"header": ["time"],
+	"functions": [
+		{
+			"name": "sha256",
+			"inputs": [
+				{"name":"answerId","type":"uint32"},
+				{"name":"data","type":"bytes"}
+			],
+			"outputs": [
+				{"name":"hash","type":"uint256"}
+			]
+		},
+		{
+			"name": "sha512",
+			"inputs": [
+				{"name":"answerId","type":"uint32"},
+				{"name":"data","type":"bytes"}
+			],
+			"outputs": [
+				{"name":"hash","type":"bytes"}
+			]
+		},
+		{
+			"name": "scrypt",
+			"inputs": [
+				{"name":"answerId","type":"uint32"},
+				{"name":"password","type":"bytes"},
+				{"name":"salt","type":"bytes"},
+				{"name":"logN","type":"uint8"},
+				{"name":"r","type":"uint32"},
+				{"name":"p","type":"uint32"},
+				{"name":"dkLen","type":"uint32"}
+			],
+			"outputs": [
+				{"name":"key","type":"bytes"}
+			]
+		},
+		{
+			"name": "naclSignDetached",
+			"inputs": [
+				{"name":"answerId","type":"uint32"},
+				{"name":"data","type":"bytes"},
+				{"name":"secretKey","type":"uint256"}
+			],
+			"outputs": [
+				{"name":"signature","type":"bytes"}
+			]
+		},
+		{
+			"name": "naclSignDetachedVerify",
+			"inputs": [
+				{"name":"answerId","type":"uint32"},
+				{"name":"data","type":"bytes"},
+				{"name":"signature","type":"bytes"},
+				{"name":"publicKey","type":"uint256"}
+			],
+			"outputs": [
+				{"name":"succeeded","type":"bool"}
+			]
+		},
+		{
+			"name": "verifySignature",
+			"inputs": [
+				{"name":"answerId","type":"uint32"},
+				{"name":"publicKey","type":"uint256"},
+				{"name":"data","type":"bytes"},
+				{"name":"signature","type":"bytes"}
+			],
+			"outputs": [
+				{"name":"valid","type":"bool"}
+			]
+		},
+		{
+			"name": "naclSecretBox",
+			"inputs": [
+				{"name":"answerId","type":"uint32"},
+				{"name":"decrypted","type":"bytes"},
+				{"name":"nonce","type":"bytes"},
+				{"name":"key","type":"uint256"}
+			],
+			"outputs": [
+				{"name":"encrypted","type":"bytes"}
+			]
+		},
+		{
+			"name": "naclSecretBoxOpen",
+			"inputs": [
+				{"name":"answerId","type":"uint32"},
+				{"name":"encrypted","type":"bytes"},
+				{"name":"nonce","type":"bytes"},
+				{"name":"key","type":"uint256"}
+			],
+			"outputs": [
+				{"name":"decrypted","type":"bytes"}
+			]
+		},
+		{
+			"name": "constructor",
+			"inputs": [
+			],
+			"outputs": [
+			]
+		}
+	],
+	"data": [
+	],
+	"events": [
+	],
+	"fields": [
+		{"name":"_pubkey","type":"uint256"},
+		{"name":"_timestamp","type":"uint64"},
+		{"name":"_constructorFlag","type":"bool"}
+	]
+}
+"#;
+
+const CRYPTO_ID: &str = "df12b8f89b61274c73291296ec828eab61202f5863680ec6003682821d77fd31";
+
+// Limits of scrypt parameters. Scrypt uses about 128 * r * 2^logN bytes of memory,
+// so DeBot can't make browser allocate more than `MAX_SCRYPT_MEMORY`.
+const MAX_SCRYPT_LOG_N: u8 = 20;
+const MAX_SCRYPT_R_P: u64 = 1 << 16;
+const MAX_SCRYPT_DK_LEN: usize = 1024;
+const MAX_SCRYPT_MEMORY: u64 = 1 << 30;
+
+/// Crypto interface. Wraps hash, KDF, signature and NaCl secret box functions of SDK.
+///
+/// Byte arguments and results are ABI `bytes`, keys are `uint256`.
+pub struct CryptoInterface {
+    ton: TonClient,
+}
+
+impl CryptoInterface {
+    pub fn new(ton: TonClient) -> Self {
+        Self { ton }
+    }
+
+    fn sha256(&self, args: &Value) -> InterfaceResult {
+        let answer_id = decode_answer_id(args)?;
+        let data = get_bytes_base64(args, "data")?;
+        let result = sha256(self.ton.clone(), ParamsOfHash { data }).map_err(|e| format!("{e}"))?;
+        Ok((answer_id, json!({ "hash": format!("0x{}", result.hash) })))
+    }
+
+    fn sha512(&self, args: &Value) -> InterfaceResult {
+        let answer_id = decode_answer_id(args)?;
+        let data = get_bytes_base64(args, "data")?;
+        let result = sha512(self.ton.clone(), ParamsOfHash { data }).map_err(|e| format!("{e}"))?;
+        Ok((answer_id, json!({ "hash": result.hash })))
+    }
+
+    fn scrypt(&self, args: &Value) -> InterfaceResult {
+        let answer_id = decode_answer_id(args)?;
+        let log_n = get_num_arg::<u8>(args, "logN")?;
+        let r = get_num_arg::<u32>(args, "r")?;
+        let p = get_num_arg::<u32>(args, "p")?;
+        let dk_len = get_num_arg::<usize>(args, "dkLen")?;
+        check_scrypt_params(log_n, r, p, dk_len)?;
+        let result = scrypt(
+            self.ton.clone(),
+            ParamsOfScrypt {
+                password: get_bytes_base64(args, "password")?,
+                salt: get_bytes_base64(args, "salt")?,
+                log_n,
+                r,
+                p,
+                dk_len,
+            },
+        )
+        .map_err(|e| format!("{e}"))?;
+        Ok((answer_id, json!({ "key": result.key })))
+    }
+
+    fn nacl_sign_detached(&self, args: &Value) -> InterfaceResult {
+        let answer_id = decode_answer_id(args)?;
+        let unsigned = get_bytes_base64(args, "data")?;
+        let secret = get_key(args, "secretKey")?;
+        // NaCl signing key is a secret key concatenated with public key.
+        let keys = nacl_sign_keypair_from_secret_key(
+            self.ton.clone(),
+            ParamsOfNaclSignKeyPairFromSecret { secret },
+        )
+        .map_err(|e| format!("{e}"))?;
+        let result = nacl_sign_detached(
+            self.ton.clone(),
+            ParamsOfNaclSign {
+                unsigned,
+                secret: keys.secret,
+            },
+        )
+        .map_err(|e| format!("{e}"))?;
+        Ok((answer_id, json!({ "signature": result.signature })))
+    }
+
+    fn nacl_sign_detached_verify(&self, args: &Value) -> InterfaceResult {
+        let answer_id = decode_answer_id(args)?;
+        let result = nacl_sign_detached_verify(
+            self.ton.clone(),
+            ParamsOfNaclSignDetachedVerify {
+                unsigned: get_bytes_base64(args, "data")?,
+                signature: get_arg(args, "signature")?,
+                public: get_key(args, "publicKey")?,
+            },
+        )
+        .map_err(|e| format!("{e}"))?;
+        Ok((answer_id, json!({ "succeeded": result.succeeded })))
+    }
+
+    /// Verifies signature of `data`. Invalid signature is not an error.
+    fn verify_signature(&self, args: &Value) -> InterfaceResult {
+        let answer_id = decode_answer_id(args)?;
+        let public = get_key(args, "publicKey")?;
+        let data = get_bytes(args, "data")?;
+        let mut signed = get_bytes(args, "signature")?;
+        signed.extend_from_slice(&data);
+        let valid = verify_signature(
+            self.ton.clone(),
+            ParamsOfVerifySignature {
+                signed: base64::encode(&signed),
+                public,
+            },
+        )
+        .map(|result| result.unsigned == base64::encode(&data))
+        .unwrap_or(false);
+        Ok((answer_id, json!({ "valid": valid })))
+    }
+
+    fn nacl_secret_box(&self, args: &Value) -> InterfaceResult {
+        let answer_id = decode_answer_id(args)?;
+        let result = nacl_secret_box(
+            self.ton.clone(),
+            ParamsOfNaclSecretBox {
+                decrypted: get_bytes_base64(args, "decrypted")?,
+                nonce: get_arg(args, "nonce")?,
+                key: get_key(args, "key")?,
+            },
+        )
+        .map_err(|e| format!("{e}"))?;
+        Ok((answer_id, json!({ "encrypted": base64_to_hex(&result.encrypted)? })))
+    }
+
+    fn nacl_secret_box_open(&self, args: &Value) -> InterfaceResult {
+        let answer_id = decode_answer_id(args)?;
+        let result = nacl_secret_box_open(
+            self.ton.clone(),
+            ParamsOfNaclSecretBoxOpen {
+                encrypted: get_bytes_base64(args, "encrypted")?,
+                nonce: get_arg(args, "nonce")?,
+                key: get_key(args, "key")?,
+            },
+        )
+        .map_err(|e| format!("{e}"))?;
+        Ok((answer_id, json!({ "decrypted": base64_to_hex(&result.decrypted)? })))
+    }
+}
+
+fn check_scrypt_params(log_n: u8, r: u32, p: u32, dk_len: usize) -> Result<(), String> {
+    if log_n > MAX_SCRYPT_LOG_N {
+        return Err(format!("scrypt logN must be at most {MAX_SCRYPT_LOG_N}"));
+    }
+    if r as u64 * p as u64 > MAX_SCRYPT_R_P {
+        return Err(format!("scrypt r * p must be at most {MAX_SCRYPT_R_P}"));
+    }
+    if dk_len > MAX_SCRYPT_DK_LEN {
+        return Err(format!("scrypt dkLen must be at most {MAX_SCRYPT_DK_LEN}"));
+    }
+    if 128 * r as u64 * (1u64 << log_n) > MAX_SCRYPT_MEMORY {
+        return Err(format!("scrypt needs more than {MAX_SCRYPT_MEMORY} bytes of memory"));
+    }
+    Ok(())
+}
+
+fn get_bytes(args: &Value, name: &str) -> Result<Vec<u8>, String> {
+    hex::decode(get_arg(args, name)?).map_err(|e| format!("invalid \"{name}\": {e}"))
+}
+
+fn get_bytes_base64(args: &Value, name: &str) -> Result<String, String> {
+    get_bytes(args, name).map(|bytes| base64::encode(&bytes))
+}
+
+/// Returns uint256 argument as a key in hex (64 characters).
+fn get_key(args: &Value, name: &str) -> Result<String, String> {
+    let key = decode_abi_bigint(&get_arg(args, name)?).map_err(|e| e.to_string())?;
+    Ok(format!("{key:064x}"))
+}
+
+fn base64_to_hex(data: &str) -> Result<String, String> {
+    base64::decode(data)
+        .map(hex::encode)
+        .map_err(|e| format!("failed to decode base64: {e}"))
+}
+
+#[async_trait::async_trait]
+impl DebotInterface for CryptoInterface {
+    fn get_id(&self) -> String {
+        CRYPTO_ID.to_string()
+    }
+
+    fn get_abi(&self) -> Abi {
+        Abi::Json(ABI.to_owned())
+    }
+
+    async fn call(&self, func: &str, args: &Value) -> InterfaceResult {
+        match func {
+            "sha256" => self.sha256(args),
+            "sha512" => self.sha512(args),
+            "scrypt" => self.scrypt(args),
+            "naclSignDetached" => self.nacl_sign_detached(args),
+            "naclSignDetachedVerify" => self.nacl_sign_detached_verify(args),
+            "verifySignature" => self.verify_signature(args),
+            "naclSecretBox" => self.nacl_secret_box(args),
+            "naclSecretBoxOpen" => self.nacl_secret_box_open(args),
+            _ => Err(format!("function \"{func}\" is not implemented")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn crypto() -> CryptoInterface {
+        CryptoInterface::new(Arc::new(ClientContext::new(ClientConfig::default()).unwrap()))
+    }
+
+    fn answer(result: InterfaceResult, name: &str) -> Value {
+        let (answer_id, params) = result.unwrap();
+        assert_eq!(answer_id, 1);
+        params[name].clone()
+    }
+
+    #[test]
+    fn test_hashes() {
+        let crypto = crypto();
+        let args = json!({ "answerId": "1", "data": hex::encode("abc") });
+        assert_eq!(
+            answer(crypto.sha256(&args), "hash"),
+            "0xba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+        assert_eq!(
+            answer(crypto.sha512(&args), "hash"),
+            "ddaf35a193617abacc417349ae20413112e6fa4e89a97ea20a9eeee64b55d39a\
+             2192992a274fc1a836ba3c23a3feebbd454d4423643ce80e2a9ac94fa54ca49f"
+        );
+    }
+
+    #[test]
+    fn test_sign_detached() {
+        let crypto = crypto();
+        let secret = "01".repeat(32);
+        let keys = nacl_sign_keypair_from_secret_key(
+            crypto.ton.clone(),
+            ParamsOfNaclSignKeyPairFromSecret {
+                secret: secret.clone(),
+            },
+        )
+        .unwrap();
+        let data = hex::encode("message");
+        let signature = answer(
+            crypto.nacl_sign_detached(&json!({
+                "answerId": "1",
+                "data": data,
+                "secretKey": format!("0x{secret}"),
+            })),
+            "signature",
+        );
+        let verify = |data: &str| {
+            answer(
+                crypto.nacl_sign_detached_verify(&json!({
+                    "answerId": "1",
+                    "data": data,
+                    "signature": signature,
+                    "publicKey": format!("0x{}", keys.public),
+                })),
+                "succeeded",
+            )
+        };
+        assert_eq!(verify(&data), json!(true));
+        assert_eq!(verify(&hex::encode("other")), json!(false));
+    }
+
+    #[test]
+    fn test_secret_box() {
+        let crypto = crypto();
+        let key = format!("0x{}", "02".repeat(32));
+        let nonce = "03".repeat(24);
+        let data = hex::encode("secret data");
+        let encrypted = answer(
+            crypto.nacl_secret_box(&json!({
+                "answerId": "1",
+                "decrypted": data,
+                "nonce": nonce,
+                "key": key,
+            })),
+            "encrypted",
+        );
+        assert_ne!(encrypted, json!(data));
+        let decrypted = answer(
+            crypto.nacl_secret_box_open(&json!({
+                "answerId": "1",
+                "encrypted": encrypted,
+                "nonce": nonce,
+                "key": key,
+            })),
+            "decrypted",
+        );
+        assert_eq!(decrypted, json!(data));
+    }
+
+    #[test]
+    fn test_scrypt_limits() {
+        let crypto = crypto();
+        let scrypt = |log_n: u8, r: u32, p: u32, dk_len: usize| {
+            crypto.scrypt(&json!({
+                "answerId": "1",
+                "password": hex::encode("password"),
+                "salt": hex::encode("salt"),
+                "logN": log_n.to_string(),
+                "r": r.to_string(),
+                "p": p.to_string(),
+                "dkLen": dk_len.to_string(),
+            }))
+        };
+        assert!(scrypt(4, 1, 1, 32).is_ok());
+        assert!(scrypt(21, 1, 1, 32).is_err());
+        assert!(scrypt(4, 1 << 8, 1 << 9, 32).is_err());
+        assert!(scrypt(4, 1, 1, 2048).is_err());
+        assert!(scrypt(20, 16, 1, 32).is_err());
+    }
+}
@@ -0,0 +1,396 @@
+use super::dinterface::{
+    decode_answer_id, get_arg, get_num_arg, DebotInterface, InterfaceResult, MAX_INPUT_ATTEMPTS,
+};
+use crate::browser::{BrowserRef, DateTimeKind, LogLevel};
+use crate::sdk_prelude::*;
+use chrono::{FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, TimeZone, Timelike};
+use serde_json::{json, Value};
+
+const ABI: &str = r#"
+{
+	"ABI version": 2,
+	"version": "2.2",
+	"header": ["time"],
+	"functions": [
+		{
+			"name": "getDate",
+			"inputs": [
+				{"name":"answerId","type":"uint32"},
+				{"name":"prompt","type":"string"},
+				{"name":"defaultDate","type":"int128"},
+				{"name":"minDate","type":"int128"},
+				{"name":"maxDate","type":"int128"}
+			],
+			"outputs": [
+				{"name":"date","type":"int128"}
+			]
+		},
+		{
+			"name": "getTime",
+			"inputs": [
+				{"name":"answerId","type":"uint32"},
+				{"name":"prompt","type":"string"},
+				{"name":"defaultTime","type":"uint32"},
+				{"name":"minTime","type":"uint32"},
+				{"name":"maxTime","type":"uint32"},
+				{"name":"minuteInterval","type":"uint8"}
+			],
+			"outputs": [
+				{"name":"time","type":"uint32"}
+			]
+		},
+		{
+			"name": "getDateTime",
+			"inputs": [
+				{"name":"answerId","type":"uint32"},
+				{"name":"prompt","type":"string"},
+				{"name":"defaultDatetime","type":"int128"},
+				{"name":"minDatetime","type":"int128"},
+				{"name":"maxDatetime","type":"int128"},
+				{"name":"minuteInterval","type":"uint8"},
+				{"name":"inTimeZoneOffset","type":"int16"}
+			],
+			"outputs": [
+				{"name":"datetime","type":"int128"},
+				{"name":"timeZoneOffset","type":"int16"}
+			]
+		},
+		{
+			"name": "getTimeZoneOffset",
+			"inputs": [
+				{"name":"answerId","type":"uint32"}
+			],
+			"outputs": [
+				{"name":"timeZoneOffset","type":"int16"}
+			]
+		},
+		{
+			"name": "constructor",
+			"inputs": [
+			],
+			"outputs": [
+			]
+		}
+	],
+	"data": [
+	],
+	"events": [
+	],
+	"fields": [
+		{"name":"_pubkey","type":"uint256"},
+		{"name":"_timestamp","type":"uint64"},
+		{"name":"_constructorFlag","type":"bool"}
+	]
+}
+"#;
+
+const DATETIME_INPUT_ID: &str = "4e862a9df81183ab425bdf0fbd76bd0b558c7f44c24887b4354bf1c26c74a623";
+
+const DATE_FORMAT: &str = "%Y-%m-%d";
+const TIME_FORMAT: &str = "%H:%M";
+const DATETIME_FORMAT: &str = "%Y-%m-%d %H:%M";
+
+/// DateTimeInput interface.
+///
+/// Dates and datetimes are unix timestamps in seconds, time is a number of seconds
+/// since midnight. Time zone offset is a number of minutes east of UTC.
+/// Browser works with strings in user time zone, engine converts them to timestamps.
+pub struct DateTimeInputInterface {
+    browser: BrowserRef,
+}
+
+impl DateTimeInputInterface {
+    pub fn new(browser: BrowserRef) -> Self {
+        Self { browser }
+    }
+
+    async fn get_date(&self, args: &Value) -> InterfaceResult {
+        let answer_id = decode_answer_id(args)?;
+        let prompt = get_arg(args, "prompt")?;
+        let tz = time_zone(self.time_zone_offset().await?)?;
+        let default = get_num_arg::<i64>(args, "defaultDate")?;
+        let min = local_datetime(&tz, get_num_arg::<i64>(args, "minDate")?)?.date();
+        let max = local_datetime(&tz, get_num_arg::<i64>(args, "maxDate")?)?.date();
+        let date = self
+            .request(
+                prompt,
+                DateTimeKind::Date,
+                format_timestamp(&tz, default, DATE_FORMAT)?,
+                min.format(DATE_FORMAT).to_string(),
+                max.format(DATE_FORMAT).to_string(),
+                |value| check_date(value, min, max),
+            )
+            .await?;
+        let midnight = date
+            .and_hms_opt(0, 0, 0)
+            .ok_or_else(|| format!("invalid date {date}"))?;
+        let date = timestamp(&tz, &midnight)?;
+        Ok((answer_id, json!({ "date": date.to_string() })))
+    }
+
+    async fn get_time(&self, args: &Value) -> InterfaceResult {
+        let answer_id = decode_answer_id(args)?;
+        let prompt = get_arg(args, "prompt")?;
+        let default = seconds_to_time(get_num_arg::<u32>(args, "defaultTime")?)?;
+        let min = seconds_to_time(get_num_arg::<u32>(args, "minTime")?)?;
+        let max = seconds_to_time(get_num_arg::<u32>(args, "maxTime")?)?;
+        let interval = get_num_arg::<u8>(args, "minuteInterval")?;
+        let time = self
+            .request(
+                prompt,
+                DateTimeKind::Time,
+                default.format(TIME_FORMAT).to_string(),
+                min.format(TIME_FORMAT).to_string(),
+                max.format(TIME_FORMAT).to_string(),
+                |value| check_time(value, min, max, interval),
+            )
+            .await?;
+        let time = time.num_seconds_from_midnight();
+        Ok((answer_id, json!({ "time": time.to_string() })))
+    }
+
+    async fn get_date_time(&self, args: &Value) -> InterfaceResult {
+        let answer_id = decode_answer_id(args)?;
+        let prompt = get_arg(args, "prompt")?;
+        let offset = get_num_arg::<i16>(args, "inTimeZoneOffset")?;
+        let tz = time_zone(offset)?;
+        let default = get_num_arg::<i64>(args, "defaultDatetime")?;
+        let min = get_num_arg::<i64>(args, "minDatetime")?;
+        let max = get_num_arg::<i64>(args, "maxDatetime")?;
+        let interval = get_num_arg::<u8>(args, "minuteInterval")?;
+        let datetime = self
+            .request(
+                prompt,
+                DateTimeKind::DateTime,
+                format_timestamp(&tz, default, DATETIME_FORMAT)?,
+                format_timestamp(&tz, min, DATETIME_FORMAT)?,
+                format_timestamp(&tz, max, DATETIME_FORMAT)?,
+                |value| check_date_time(value, &tz, min, max, interval),
+            )
+            .await?;
+        Ok((
+            answer_id,
+            json!({ "datetime": datetime.to_string(), "timeZoneOffset": offset.to_string() }),
+        ))
+    }
+
+    async fn get_time_zone_offset(&self, args: &Value) -> InterfaceResult {
+        let answer_id = decode_answer_id(args)?;
+        let offset = self.time_zone_offset().await?;
+        Ok((answer_id, json!({ "timeZoneOffset": offset.to_string() })))
+    }
+
+    async fn time_zone_offset(&self) -> Result<i16, String> {
+        self.browser
+            .get_time_zone_offset()
+            .await
+            .map_err(|e| format!("{e}"))
+    }
+
+    /// Requests value from user until it passes `check` or attempts are exhausted.
+    async fn request<T, F>(
+        &self,
+        prompt: String,
+        kind: DateTimeKind,
+        default: String,
+        min: String,
+        max: String,
+        check: F,
+    ) -> Result<T, String>
+    where
+        F: Fn(&str) -> Result<T, String>,
+    {
+        let mut attempt = 1;
+        loop {
+            let value = self
+                .browser
+                .input_datetime(
+                    prompt.clone(),
+                    kind.clone(),
+                    default.clone(),
+                    min.clone(),
+                    max.clone(),
+                )
+                .await
+                .map_err(|e| format!("{e}"))?;
+            match check(value.trim()) {
+                Ok(result) => return Ok(result),
+                Err(e) if attempt < MAX_INPUT_ATTEMPTS => {
+                    self.browser.log(LogLevel::User, format!("{e}. Try again."));
+                    attempt += 1;
+                }
+                Err(e) => return Err(e),
+            }
+        }
+    }
+}
+
+fn time_zone(offset: i16) -> Result<FixedOffset, String> {
+    FixedOffset::east_opt(offset as i32 * 60)
+        .ok_or_else(|| format!("invalid time zone offset: {offset}"))
+}
+
+fn local_datetime(tz: &FixedOffset, ts: i64) -> Result<NaiveDateTime, String> {
+    tz.timestamp_opt(ts, 0)
+        .single()
+        .map(|dt| dt.naive_local())
+        .ok_or_else(|| format!("invalid timestamp: {ts}"))
+}
+
+fn format_timestamp(tz: &FixedOffset, ts: i64, format: &str) -> Result<String, String> {
+    local_datetime(tz, ts).map(|dt| dt.format(format).to_string())
+}
+
+fn timestamp(tz: &FixedOffset, datetime: &NaiveDateTime) -> Result<i64, String> {
+    tz.from_local_datetime(datetime)
+        .single()
+        .map(|dt| dt.timestamp())
+        .ok_or_else(|| format!("invalid date and time: {datetime}"))
+}
+
+fn seconds_to_time(seconds: u32) -> Result<NaiveTime, String> {
+    NaiveTime::from_num_seconds_from_midnight_opt(seconds, 0)
+        .ok_or_else(|| format!("invalid time: {seconds}"))
+}
+
+fn check_date(value: &str, min: NaiveDate, max: NaiveDate) -> Result<NaiveDate, String> {
+    let date = NaiveDate::parse_from_str(value, DATE_FORMAT)
+        .map_err(|e| format!("invalid date \"{value}\": {e}"))?;
+    if date < min || date > max {
+        return Err(format!("date {date} is out of range [{min}, {max}]"));
+    }
+    Ok(date)
+}
+
+fn check_time(
+    value: &str,
+    min: NaiveTime,
+    max: NaiveTime,
+    interval: u8,
+) -> Result<NaiveTime, String> {
+    let time = NaiveTime::parse_from_str(value, TIME_FORMAT)
+        .map_err(|e| format!("invalid time \"{value}\": {e}"))?;
+    if time < min || time > max {
+        return Err(format!(
+            "time {} is out of range [{}, {}]",
+            time.format(TIME_FORMAT),
+            min.format(TIME_FORMAT),
+            max.format(TIME_FORMAT),
+        ));
+    }
+    check_minute_interval(time.minute(), interval)?;
+    Ok(time)
+}
+
+/// Checks date and time entered in time zone `tz` and returns its timestamp.
+fn check_date_time(
+    value: &str,
+    tz: &FixedOffset,
+    min: i64,
+    max: i64,
+    interval: u8,
+) -> Result<i64, String> {
+    let datetime = NaiveDateTime::parse_from_str(value, DATETIME_FORMAT)
+        .map_err(|e| format!("invalid date and time \"{value}\": {e}"))?;
+    let ts = timestamp(tz, &datetime)?;
+    if ts < min || ts > max {
+        return Err(format!(
+            "date and time {} is out of range [{}, {}]",
+            datetime.format(DATETIME_FORMAT),
+            format_timestamp(tz, min, DATETIME_FORMAT)?,
+            format_timestamp(tz, max, DATETIME_FORMAT)?,
+        ));
+    }
+    check_minute_interval(datetime.minute(), interval)?;
+    Ok(ts)
+}
+
+fn check_minute_interval(minute: u32, interval: u8) -> Result<(), String> {
+    if interval > 1 && minute % interval as u32 != 0 {
+        return Err(format!("minutes must be a multiple of {interval}"));
+    }
+    Ok(())
+}
+
+#[async_trait::async_trait]
+impl DebotInterface for DateTimeInputInterface {
+    fn get_id(&self) -> String {
+        DATETIME_INPUT_ID.to_string()
+    }
+
+    fn get_abi(&self) -> Abi {
+        Abi::Json(ABI.to_owned())
+    }
+
+    async fn call(&self, func: &str, args: &Value) -> InterfaceResult {
+        match func {
+            "getDate" => self.get_date(args).await,
+            "getTime" => self.get_time(args).await,
+            "getDateTime" => self.get_date_time(args).await,
+            "getTimeZoneOffset" => self.get_time_zone_offset(args).await,
+            _ => Err(format!("function \"{func}\" is not implemented")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 2024-03-10 12:30:00 UTC
+    const TS: i64 = 1710073800;
+
+    #[test]
+    fn test_time_zone_offsets() {
+        let utc = time_zone(0).unwrap();
+        let msk = time_zone(180).unwrap();
+        let nyc = time_zone(-300).unwrap();
+        assert_eq!(format_timestamp(&utc, TS, DATETIME_FORMAT).unwrap(), "2024-03-10 12:30");
+        assert_eq!(format_timestamp(&msk, TS, DATETIME_FORMAT).unwrap(), "2024-03-10 15:30");
+        assert_eq!(format_timestamp(&nyc, TS, DATETIME_FORMAT).unwrap(), "2024-03-10 07:30");
+        // local time converts back to the same timestamp in any time zone.
+        assert_eq!(check_date_time("2024-03-10 15:30", &msk, 0, i64::MAX, 0), Ok(TS));
+        assert_eq!(check_date_time("2024-03-10 07:30", &nyc, 0, i64::MAX, 0), Ok(TS));
+        // date boundary depends on time zone.
+        let late = TS + 10 * 3600;
+        assert_eq!(format_timestamp(&utc, late, DATE_FORMAT).unwrap(), "2024-03-10");
+        assert_eq!(format_timestamp(&msk, late, DATE_FORMAT).unwrap(), "2024-03-11");
+        assert!(time_zone(24 * 60).is_err());
+        assert!(time_zone(-24 * 60).is_err());
+    }
+
+    #[test]
+    fn test_date_bounds() {
+        let min = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+        let max = NaiveDate::from_ymd_opt(2024, 12, 31).unwrap();
+        assert_eq!(check_date("2024-01-01", min, max), Ok(min));
+        assert_eq!(check_date("2024-12-31", min, max), Ok(max));
+        assert!(check_date("2023-12-31", min, max).is_err());
+        assert!(check_date("2025-01-01", min, max).is_err());
+        assert!(check_date("2024-02-30", min, max).is_err());
+        assert!(check_date("01.01.2024", min, max).is_err());
+    }
+
+    #[test]
+    fn test_time_bounds() {
+        let min = seconds_to_time(9 * 3600).unwrap();
+        let max = seconds_to_time(18 * 3600).unwrap();
+        assert_eq!(check_time("09:00", min, max, 15), Ok(min));
+        assert_eq!(check_time("18:00", min, max, 15), Ok(max));
+        assert!(check_time("08:59", min, max, 1).is_err());
+        assert!(check_time("18:01", min, max, 1).is_err());
+        assert!(check_time("12:10", min, max, 15).is_err());
+        assert!(check_time("25:00", min, max, 1).is_err());
+        assert!(seconds_to_time(24 * 3600).is_err());
+    }
+
+    #[test]
+    fn test_date_time_bounds() {
+        let utc = time_zone(0).unwrap();
+        assert_eq!(check_date_time("2024-03-10 12:30", &utc, TS, TS, 30), Ok(TS));
+        assert!(check_date_time("2024-03-10 12:29", &utc, TS, TS + 60, 1).is_err());
+        assert!(check_date_time("2024-03-10 12:32", &utc, TS, TS + 60, 1).is_err());
+        assert!(check_date_time("2024-03-10 12:31", &utc, TS, TS + 60, 15).is_err());
+        assert!(local_datetime(&utc, i64::MAX).is_err());
+    }
+}
@@ -1,7 +1,11 @@
 use super::{
-    json_lib_utils::bypass_json, Base64Interface, HexInterface, NetworkInterface, QueryInterface,
-    SdkInterface,
+    json_lib_utils::bypass_json, AbiInterface, AddressInputInterface, AddressInterface,
+    AmountInputInterface, Base64Interface, BocInterface, ConfirmInputInterface, CryptoInterface,
+    DateTimeInputInterface, HexInterface, MediaInterface, MenuInterface, NetworkInterface,
+    NetworkV2Interface, NumberInputInterface, QRCodeInterface, QueryInterface, SdkInterface,
+    StringsInterface, TerminalInterface,
 };
+use crate::network_policy::NetworkPolicy;
 use crate::sdk_prelude::{abi_to_json_string, deserialize_cell_from_boc};
 use crate::{JsonValue, TonClient};
 use num_traits::cast::NumCast;
@@ -19,8 +23,16 @@ use ton_client::{
 use ton_sdk::AbiContract;
 use ton_types::SliceData;
 use crate::browser::{BrowserRef, debug, log, LogLevel};
+/// Answer ID and answer arguments. Null arguments mean that interface answers later.
 pub type InterfaceResult = Result<(u32, Value), String>;
 
+/// Number of attempts given to user to enter valid value in input interfaces.
+pub(crate) const MAX_INPUT_ATTEMPTS: usize = 3;
+
+/// Address returned by interfaces when there is no address to return.
+pub(crate) const ZERO_ADDRESS: &str =
+    "0:0000000000000000000000000000000000000000000000000000000000000000";
+
 async fn decode_msg(
     client: TonClient,
     msg_body: String,
@@ -132,7 +144,7 @@ pub trait DebotInterfaceExecutor {
                     .call(&func, &args)
                     .await
                     .map_err(|e| format!("{interface_id}.{func} failed: {e}"))?;
-                if abi_version == "2.0" {
+                if abi_version == "2.0" && !ret_args.is_null() {
                     if let Abi::Json(json_str) = abi {
                         let _ = convert_return_args(json_str.as_str(), &func, &mut ret_args)?;
                     }
@@ -188,8 +200,13 @@ impl BuiltinInterfaces {
         let iface: Arc<dyn DebotInterface + Send + Sync> = Arc::new(HexInterface::new());
         interfaces.insert(iface.get_id(), iface);
 
+        let policy = Arc::new(NetworkPolicy::default());
         let iface: Arc<dyn DebotInterface + Send + Sync> =
-            Arc::new(NetworkInterface::new(browser.clone()));
+            Arc::new(NetworkInterface::new(browser.clone(), policy.clone()));
+        interfaces.insert(iface.get_id(), iface);
+
+        let iface: Arc<dyn DebotInterface + Send + Sync> =
+            Arc::new(NetworkV2Interface::new(browser.clone(), policy));
         interfaces.insert(iface.get_id(), iface);
 
         let iface: Arc<dyn DebotInterface + Send + Sync> =
@@ -200,6 +217,61 @@ impl BuiltinInterfaces {
             Arc::new(SdkInterface::new(client.clone(), browser.clone()));
         interfaces.insert(iface.get_id(), iface);
 
+        let iface: Arc<dyn DebotInterface + Send + Sync> =
+            Arc::new(TerminalInterface::new(client.clone(), browser.clone()));
+        interfaces.insert(iface.get_id(), iface);
+
+        let iface: Arc<dyn DebotInterface + Send + Sync> =
+            Arc::new(MenuInterface::new(browser.clone()));
+        interfaces.insert(iface.get_id(), iface);
+
+        let iface: Arc<dyn DebotInterface + Send + Sync> =
+            Arc::new(AddressInputInterface::new(browser.clone()));
+        interfaces.insert(iface.get_id(), iface);
+
+        let iface: Arc<dyn DebotInterface + Send + Sync> =
+            Arc::new(AmountInputInterface::new(browser.clone()));
+        interfaces.insert(iface.get_id(), iface);
+
+        let iface: Arc<dyn DebotInterface + Send + Sync> =
+            Arc::new(ConfirmInputInterface::new(browser.clone()));
+        interfaces.insert(iface.get_id(), iface);
+
+        let iface: Arc<dyn DebotInterface + Send + Sync> =
+            Arc::new(NumberInputInterface::new(browser.clone()));
+        interfaces.insert(iface.get_id(), iface);
+
+        let iface: Arc<dyn DebotInterface + Send + Sync> =
+            Arc::new(DateTimeInputInterface::new(browser.clone()));
+        interfaces.insert(iface.get_id(), iface);
+
+        let iface: Arc<dyn DebotInterface + Send + Sync> =
+            Arc::new(QRCodeInterface::new(browser.clone()));
+        interfaces.insert(iface.get_id(), iface);
+
+        let iface: Arc<dyn DebotInterface + Send + Sync> =
+            Arc::new(MediaInterface::new(browser.clone()));
+        interfaces.insert(iface.get_id(), iface);
+
+        let iface: Arc<dyn DebotInterface + Send + Sync> =
+            Arc::new(CryptoInterface::new(client.clone()));
+        interfaces.insert(iface.get_id(), iface);
+
+        let iface: Arc<dyn DebotInterface + Send + Sync> =
+            Arc::new(AddressInterface::new(client.clone()));
+        interfaces.insert(iface.get_id(), iface);
+
+        let iface: Arc<dyn DebotInterface + Send + Sync> =
+            Arc::new(BocInterface::new(client.clone()));
+        interfaces.insert(iface.get_id(), iface);
+
+        let iface: Arc<dyn DebotInterface + Send + Sync> =
+            Arc::new(AbiInterface::new(client.clone()));
+        interfaces.insert(iface.get_id(), iface);
+
+        let iface: Arc<dyn DebotInterface + Send + Sync> = Arc::new(StringsInterface::new());
+        interfaces.insert(iface.get_id(), iface);
+
         Self { client, interfaces, browser }
     }
 
@@ -237,6 +309,39 @@ pub fn get_bool_arg(args: &Value, name: &str) -> Result<bool, String> {
     args[name].as_bool().ok_or(format!("\"{name}\" not found"))
 }
 
+/// Converts decimal string (e.g. "1.5") to integer with `decimals` digits in fractional part.
+pub(crate) fn parse_decimal(value: &str, decimals: u8) -> Result<u128, String> {
+    let err = || format!("invalid amount \"{value}\"");
+    let (integer, fraction) = value.split_once('.').unwrap_or((value, ""));
+    // amount is unsigned, sign is not allowed.
+    if (integer.is_empty() && fraction.is_empty()) || !integer.chars().all(|c| c.is_ascii_digit())
+    {
+        return Err(err());
+    }
+    if fraction.len() > decimals as usize || !fraction.chars().all(|c| c.is_ascii_digit()) {
+        return Err(format!(
+            "invalid amount \"{value}\": at most {decimals} digits after point are allowed"
+        ));
+    }
+    let integer = if integer.is_empty() {
+        0
+    } else {
+        integer.parse::<u128>().map_err(|_| err())?
+    };
+    let width = decimals as usize;
+    let fraction = format!("{fraction:0<width$}");
+    let fraction = if fraction.is_empty() {
+        0
+    } else {
+        fraction.parse::<u128>().map_err(|_| err())?
+    };
+    10u128
+        .checked_pow(decimals as u32)
+        .and_then(|m| integer.checked_mul(m))
+        .and_then(|v| v.checked_add(fraction))
+        .ok_or_else(err)
+}
+
 pub fn get_array_strings(args: &Value, name: &str) -> Result<Vec<String>, String> {
     let array = args[name]
         .as_array()
@@ -250,3 +355,36 @@ pub fn get_array_strings(args: &Value, name: &str) -> Result<Vec<String>, String
     }
     Ok(strings)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::parse_decimal;
+
+    #[test]
+    fn test_parse_decimal() {
+        assert_eq!(parse_decimal("1.5", 9), Ok(1_500_000_000));
+        assert_eq!(parse_decimal("1.", 9), Ok(1_000_000_000));
+        assert_eq!(parse_decimal(".25", 2), Ok(25));
+        assert_eq!(parse_decimal("42", 0), Ok(42));
+        assert_eq!(parse_decimal("007.10", 3), Ok(7100));
+        assert!(parse_decimal("", 9).is_err());
+        assert!(parse_decimal(".", 9).is_err());
+        assert!(parse_decimal("1.1234567891", 9).is_err());
+        assert!(parse_decimal("1.5", 0).is_err());
+        assert!(parse_decimal("1.5e3", 9).is_err());
+        assert!(parse_decimal("+1", 9).is_err());
+        assert!(parse_decimal("-1", 9).is_err());
+        assert!(parse_decimal("1.-5", 9).is_err());
+        assert!(parse_decimal("1 000", 9).is_err());
+    }
+
+    #[test]
+    fn test_parse_decimal_overflow() {
+        assert_eq!(parse_decimal(&u128::MAX.to_string(), 0), Ok(u128::MAX));
+        assert!(parse_decimal("340282366920938463463374607431768211456", 0).is_err());
+        // integer part fits into u128, but not after multiplication by 10^decimals.
+        assert!(parse_decimal("340282366920938463463374607431768211455", 1).is_err());
+        assert!(parse_decimal("340282366920938463463374607431.768211456", 9).is_err());
+        assert!(parse_decimal("1", 39).is_err());
+    }
+}
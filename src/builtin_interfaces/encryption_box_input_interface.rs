@@ -0,0 +1,215 @@
+use super::dinterface::{decode_answer_id, get_arg, get_num_arg, DebotInterface, InterfaceResult};
+use super::signing_box_input_interface::{decode_public_key, select_key};
+use crate::boxes::BoxRegistry;
+use crate::browser::BrowserRef;
+use crate::keys::KeyProviderRef;
+use crate::sdk_prelude::*;
+use serde_json::{json, Value};
+use ton_client::crypto::{
+    AesParamsEB, ChaCha20ParamsEB, CipherMode, EncryptionAlgorithm, NaclBoxParamsEB,
+    NaclSecretBoxParamsEB,
+};
+
+const ABI: &str = r#"
+{
+	"ABI version": 2,
+	"version": "2.2",
+	"header": ["time"],
+	"functions": [
+		{
+			"name": "getNaclBox",
+			"id": "0x6d19198c",
+			"inputs": [
+				{"name":"answerId","type":"uint32"},
+				{"name":"prompt","type":"string"},
+				{"name":"nonce","type":"bytes"},
+				{"name":"theirPubkey","type":"uint256"}
+			],
+			"outputs": [
+				{"name":"handle","type":"uint32"}
+			]
+		},
+		{
+			"name": "getNaclSecretBox",
+			"id": "0x537251c8",
+			"inputs": [
+				{"name":"answerId","type":"uint32"},
+				{"name":"prompt","type":"string"},
+				{"name":"nonce","type":"bytes"}
+			],
+			"outputs": [
+				{"name":"handle","type":"uint32"}
+			]
+		},
+		{
+			"name": "getChaCha20Box",
+			"id": "0x7a9e536a",
+			"inputs": [
+				{"name":"answerId","type":"uint32"},
+				{"name":"prompt","type":"string"},
+				{"name":"nonce","type":"bytes"}
+			],
+			"outputs": [
+				{"name":"handle","type":"uint32"}
+			]
+		},
+		{
+			"name": "getAesBox",
+			"inputs": [
+				{"name":"answerId","type":"uint32"},
+				{"name":"prompt","type":"string"},
+				{"name":"iv","type":"bytes"}
+			],
+			"outputs": [
+				{"name":"handle","type":"uint32"}
+			]
+		},
+		{
+			"name": "remove",
+			"id": "0x542f817e",
+			"inputs": [
+				{"name":"answerId","type":"uint32"},
+				{"name":"handle","type":"uint32"}
+			],
+			"outputs": [
+				{"name":"removed","type":"bool"}
+			]
+		},
+		{
+			"name": "getSupportedAlgorithms",
+			"id": "0x3f9d909f",
+			"inputs": [
+				{"name":"answerId","type":"uint32"}
+			],
+			"outputs": [
+				{"name":"names","type":"string[]"}
+			]
+		},
+		{
+			"name": "constructor",
+			"id": "0x68b55f3f",
+			"inputs": [
+			],
+			"outputs": [
+			]
+		}
+	],
+	"data": [
+	],
+	"events": [
+	],
+	"fields": [
+		{"name":"_pubkey","type":"uint256"},
+		{"name":"_timestamp","type":"uint64"},
+		{"name":"_constructorFlag","type":"bool"}
+	]
+}
+"#;
+
+const ENCRYPTION_BOX_INPUT_ID: &str =
+    "5b5f76b54d976d72f1ada3063d1af2e5352edaf1ba86b3b311170d4d81056d61";
+
+pub struct EncryptionBoxInputInterface {
+    keys: KeyProviderRef,
+    browser: BrowserRef,
+    boxes: Arc<BoxRegistry>,
+}
+
+impl EncryptionBoxInputInterface {
+    pub fn new(keys: KeyProviderRef, browser: BrowserRef, boxes: Arc<BoxRegistry>) -> Self {
+        Self {
+            keys,
+            browser,
+            boxes,
+        }
+    }
+
+    async fn get_nacl_box(&self, args: &Value) -> InterfaceResult {
+        let their_public = decode_public_key(&get_arg(args, "theirPubkey")?)?;
+        let algorithm = EncryptionAlgorithm::NaclBox(NaclBoxParamsEB {
+            their_public,
+            secret: String::new(),
+            nonce: get_arg(args, "nonce")?,
+        });
+        self.create_box(args, algorithm).await
+    }
+
+    async fn get_nacl_secret_box(&self, args: &Value) -> InterfaceResult {
+        let algorithm = EncryptionAlgorithm::NaclSecretBox(NaclSecretBoxParamsEB {
+            key: String::new(),
+            nonce: get_arg(args, "nonce")?,
+        });
+        self.create_box(args, algorithm).await
+    }
+
+    async fn get_chacha20_box(&self, args: &Value) -> InterfaceResult {
+        let algorithm = EncryptionAlgorithm::ChaCha20(ChaCha20ParamsEB {
+            key: String::new(),
+            nonce: get_arg(args, "nonce")?,
+        });
+        self.create_box(args, algorithm).await
+    }
+
+    async fn get_aes_box(&self, args: &Value) -> InterfaceResult {
+        let algorithm = EncryptionAlgorithm::AES(AesParamsEB {
+            mode: CipherMode::CBC,
+            key: String::new(),
+            iv: get_arg(args, "iv")?,
+        });
+        self.create_box(args, algorithm).await
+    }
+
+    /// Requests user to choose key and creates encryption box with it.
+    async fn create_box(&self, args: &Value, algorithm: EncryptionAlgorithm) -> InterfaceResult {
+        let answer_id = decode_answer_id(args)?;
+        let prompt = get_arg(args, "prompt")?;
+        let public = select_key(&self.keys, &self.browser, prompt, &[]).await?;
+        let handle = self
+            .keys
+            .get_encryption_box(&public, algorithm)
+            .await
+            .map_err(|e| format!("{e}"))?;
+        self.boxes.add_encryption_box(&handle);
+        Ok((answer_id, json!({ "handle": handle.0 })))
+    }
+
+    fn remove(&self, args: &Value) -> InterfaceResult {
+        let answer_id = decode_answer_id(args)?;
+        let handle = get_num_arg::<u32>(args, "handle")?;
+        let removed = self
+            .boxes
+            .release_encryption_box(&EncryptionBoxHandle(handle));
+        Ok((answer_id, json!({ "removed": removed })))
+    }
+
+    fn get_supported_algorithms(&self, args: &Value) -> InterfaceResult {
+        let answer_id = decode_answer_id(args)?;
+        Ok((
+            answer_id,
+            json!({ "names": ["NaclBox", "NaclSecretBox", "ChaCha20"] }),
+        ))
+    }
+}
+
+#[async_trait::async_trait]
+impl DebotInterface for EncryptionBoxInputInterface {
+    fn get_id(&self) -> String {
+        ENCRYPTION_BOX_INPUT_ID.to_string()
+    }
+
+    fn get_abi(&self) -> Abi {
+        Abi::Json(ABI.to_owned())
+    }
+
+    async fn call(&self, func: &str, args: &Value) -> InterfaceResult {
+        match func {
+            "getNaclBox" => self.get_nacl_box(args).await,
+            "getNaclSecretBox" => self.get_nacl_secret_box(args).await,
+            "getChaCha20Box" => self.get_chacha20_box(args).await,
+            "getAesBox" => self.get_aes_box(args).await,
+            "remove" => self.remove(args),
+            "getSupportedAlgorithms" => self.get_supported_algorithms(args),
+            _ => Err(format!("function \"{func}\" is not implemented")),
+        }
+    }
+}
@@ -1,8 +1,14 @@
-use super::dinterface::{decode_answer_id, get_arg, DebotInterface, InterfaceResult};
-use super::json_lib_utils::{bypass_json, pack};
+use super::dinterface::{
+    decode_answer_id, get_arg, get_bool_arg, get_num_arg, DebotInterface, InterfaceResult,
+};
+use super::json_lib_utils::{
+    bypass_json, pack_exact, typed_json, unpack_exact, ExactJson, Value,
+};
+use crate::sdk_prelude::{deserialize_cell_from_base64, slice_from_cell};
 use serde_json::json;
 use serde_json::Value as JsonValue;
-use ton_abi::{Contract, ParamType};
+use ton_abi::token::Detokenizer;
+use ton_abi::{Contract, Function, Param, ParamType, TokenValue};
 use ton_client::abi::Abi;
 
 const ABI: &str = r#"
@@ -33,6 +39,53 @@ const ABI: &str = r#"
 				{"name":"result","type":"bool"},
 				{"components":[{"name":"kind","type":"uint8"},{"name":"value","type":"cell"},{"name":"object","type":"map(uint256,cell)"},{"components":[{"name":"cell","type":"cell"}],"name":"array","type":"tuple[]"}],"name":"obj","type":"tuple"}
 			]
+		},
+		{
+			"name": "parseDecimals",
+			"inputs": [
+				{"name":"answerId","type":"uint32"},
+				{"name":"json","type":"string"}
+			],
+			"outputs": [
+				{"name":"result","type":"bool"},
+				{"components":[{"name":"kind","type":"uint8"},{"name":"value","type":"cell"},{"name":"object","type":"map(uint256,cell)"},{"components":[{"name":"cell","type":"cell"}],"name":"array","type":"tuple[]"}],"name":"obj","type":"tuple"}
+			]
+		},
+		{
+			"name": "serialize",
+			"inputs": [
+				{"name":"answerId","type":"uint32"},
+				{"name":"typeId","type":"uint32"},
+				{"name":"obj","type":"cell"}
+			],
+			"outputs": [
+				{"name":"result","type":"bool"},
+				{"name":"json","type":"string"}
+			]
+		},
+		{
+			"name": "query",
+			"inputs": [
+				{"name":"answerId","type":"uint32"},
+				{"name":"json","type":"string"},
+				{"name":"path","type":"string"},
+				{"name":"decimals","type":"bool"}
+			],
+			"outputs": [
+				{"name":"result","type":"bool"},
+				{"components":[{"name":"kind","type":"uint8"},{"name":"value","type":"cell"},{"name":"object","type":"map(uint256,cell)"},{"components":[{"name":"cell","type":"cell"}],"name":"array","type":"tuple[]"}],"name":"obj","type":"tuple"}
+			]
+		},
+		{
+			"name": "unpack",
+			"inputs": [
+				{"name":"answerId","type":"uint32"},
+				{"components":[{"name":"kind","type":"uint8"},{"name":"value","type":"cell"},{"name":"object","type":"map(uint256,cell)"},{"components":[{"name":"cell","type":"cell"}],"name":"array","type":"tuple[]"}],"name":"obj","type":"tuple"}
+			],
+			"outputs": [
+				{"name":"result","type":"bool"},
+				{"name":"json","type":"string"}
+			]
 		}
     ]
 }
@@ -40,6 +93,13 @@ const ABI: &str = r#"
 
 const BASE64_ID: &str = "442288826041d564ccedc579674f17c1b0a3452df799656a9167a41ab270ec19";
 
+/// Json interface. Parses, queries and serializes JSON for DeBots.
+///
+/// JSON values are packed into `obj` tuples of kinds: 0 - string, 1 - number, 2 - bool,
+/// 3 - array, 4 - object, 5 - null, 6 - cell. Integers which fit into int256 are numbers,
+/// other numbers are strings with their JSON text. DeBot can opt into kind 7 (decimal,
+/// value is the number as written in JSON) with `parseDecimals` or `decimals` argument
+/// of `query`. Packed objects don't keep key order, `unpack` returns keys sorted.
 pub struct JsonInterface {
     debot_abi: String,
 }
@@ -66,12 +126,12 @@ impl JsonInterface {
         ))
     }
 
-    fn parse(&self, args: &JsonValue) -> InterfaceResult {
+    fn parse(&self, args: &JsonValue, decimals: bool) -> InterfaceResult {
         let answer_id = decode_answer_id(args)?;
         let json_str = get_arg(args, "json")?;
-        let json_obj: JsonValue = serde_json::from_str(&json_str)
+        let json_obj = ExactJson::parse(&json_str)
             .map_err(|e| format!("argument \"json\" is not a valid json: {e}"))?;
-        let result = pack(json_obj);
+        let result = pack_exact(json_obj, decimals);
         Ok((
             answer_id,
             json!({
@@ -81,26 +141,98 @@ impl JsonInterface {
         ))
     }
 
+    fn serialize(&self, args: &JsonValue) -> InterfaceResult {
+        let answer_id = decode_answer_id(args)?;
+        let type_id = get_num_arg::<u32>(args, "typeId")?;
+        let obj = get_arg(args, "obj")?;
+        let (result, json_str) = match self.serialize_cell(&obj, type_id) {
+            Ok(json) => (true, json.to_string()),
+            Err(_) => (false, String::new()),
+        };
+        Ok((
+            answer_id,
+            json!({
+                "result": result,
+                "json": json_str,
+            }),
+        ))
+    }
+
+    fn query(&self, args: &JsonValue) -> InterfaceResult {
+        let answer_id = decode_answer_id(args)?;
+        let json_str = get_arg(args, "json")?;
+        let path = get_arg(args, "path")?;
+        let decimals = get_bool_arg(args, "decimals")?;
+        let found = ExactJson::parse(&json_str)
+            .and_then(|json| json.query(&path))
+            .ok()
+            .flatten()
+            .and_then(|json| pack_exact(json, decimals));
+        Ok((
+            answer_id,
+            json!({
+                "result": found.is_some(),
+                "obj": found.unwrap_or_default(),
+            }),
+        ))
+    }
+
+    fn unpack(&self, args: &JsonValue) -> InterfaceResult {
+        let answer_id = decode_answer_id(args)?;
+        let obj = args
+            .get("obj")
+            .ok_or_else(|| "\"obj\" not found".to_string())?;
+        let json = Value::from_abi_json(obj.clone()).and_then(unpack_exact);
+        Ok((
+            answer_id,
+            json!({
+                "result": json.is_some(),
+                "json": json.map(|j| j.to_string()).unwrap_or_default(),
+            }),
+        ))
+    }
+
+    /// Decodes cell as `obj` tuple of DeBot function `type_id` and converts it to JSON.
+    fn serialize_cell(&self, obj: &str, type_id: u32) -> Result<ExactJson, String> {
+        let contract = Contract::load(self.debot_abi.as_bytes()).map_err(|e| format!("{e}"))?;
+        let func = contract
+            .function_by_id(type_id, true)
+            .map_err(|_| format!("function with id {type_id} not found"))?;
+        let params = obj_params(func)?;
+        let (_, cell) = deserialize_cell_from_base64(obj, "obj").map_err(|e| e.to_string())?;
+        let slice = slice_from_cell(cell).map_err(|e| e.to_string())?;
+        let tokens = TokenValue::decode_params(&params, slice, &func.abi_version, false)
+            .map_err(|e| format!("failed to decode obj: {e}"))?;
+        let value = Detokenizer::detokenize_to_json_value(&tokens).map_err(|e| format!("{e}"))?;
+        Ok(typed_json(&value, &ParamType::Tuple(params)))
+    }
+
     fn deserialize_json(&self, json_obj: &mut JsonValue, answer_id: u32) -> Result<(), String> {
         let contract = Contract::load(self.debot_abi.as_bytes()).map_err(|e| format!("{e}"))?;
         let func = contract
             .function_by_id(answer_id, true)
             .map_err(|_| format!("function with id {answer_id} not found"))?;
-        let obj = func
-            .inputs
-            .iter()
-            .find(|e| e.name == "obj")
-            .ok_or_else(|| "\"obj\" argument not found".to_string())?;
-        if let ParamType::Tuple(params) = &obj.kind {
-            for p in params {
-                let pointer = "";
-                bypass_json(pointer, json_obj, p.clone(), ParamType::Bytes)?;
-            }
+        for p in obj_params(func)? {
+            let pointer = "";
+            bypass_json(pointer, json_obj, p, ParamType::Bytes)?;
         }
         Ok(())
     }
 }
 
+/// Returns components of `obj` tuple argument of DeBot function.
+fn obj_params(func: &Function) -> Result<Vec<Param>, String> {
+    let obj = func
+        .inputs
+        .iter()
+        .find(|e| e.name == "obj")
+        .ok_or_else(|| "\"obj\" argument not found".to_string())?;
+    match &obj.kind {
+        ParamType::Tuple(params) => Ok(params.clone()),
+        _ => Ok(vec![]),
+    }
+}
+
 #[async_trait::async_trait]
 impl DebotInterface for JsonInterface {
     fn get_id(&self) -> String {
@@ -114,7 +246,11 @@ impl DebotInterface for JsonInterface {
     async fn call(&self, func: &str, args: &JsonValue) -> InterfaceResult {
         match func {
             "deserialize" => self.deserialize(args),
-            "parse" => self.parse(args),
+            "parse" => self.parse(args, false),
+            "parseDecimals" => self.parse(args, true),
+            "serialize" => self.serialize(args),
+            "query" => self.query(args),
+            "unpack" => self.unpack(args),
             _ => Err(format!("function \"{func}\" is not implemented")),
         }
     }
@@ -122,8 +258,10 @@ impl DebotInterface for JsonInterface {
 
 #[cfg(test)]
 mod tests {
+    use super::super::json_lib_utils::{typed_json, ExactJson};
     use super::JsonInterface;
     use serde_json::json;
+    use ton_abi::{Param, ParamType};
 
     const ABI: &str = r#"
     {
@@ -204,4 +342,73 @@ mod tests {
             })
         );
     }
+
+    #[test]
+    fn test_exact_numbers() {
+        let json = r#"{"big": 123456789012345678901234567890, "price": 0.1000, "list": [1, -2e3]}"#;
+        let parsed = ExactJson::parse(json).unwrap();
+        assert_eq!(
+            parsed.to_string(),
+            r#"{"big":123456789012345678901234567890,"price":0.1000,"list":[1,-2e3]}"#
+        );
+        let dup = ExactJson::parse(r#"{"b": 1, "a": 2, "b": 3}"#).unwrap();
+        assert_eq!(dup.to_string(), r#"{"b":1,"a":2,"b":3}"#);
+        assert_eq!(dup.query("$.b").unwrap().map(|v| v.to_string()), Some("3".to_owned()));
+        assert!(ExactJson::parse("{\"a\":").is_err());
+    }
+
+    #[test]
+    fn test_decimals_opt_in() {
+        let json_iface = JsonInterface::new(ABI);
+        let kind = |result: super::InterfaceResult| result.unwrap().1["obj"]["kind"].clone();
+        let args = json!({ "answerId": "1", "json": "0.10" });
+        assert_eq!(kind(json_iface.parse(&args, false)), json!(0));
+        assert_eq!(kind(json_iface.parse(&args, true)), json!(7));
+        let query = |decimals: bool| {
+            json_iface.query(&json!({
+                "answerId": "1",
+                "json": r#"{"price": 0.10, "count": 3}"#,
+                "path": "$.price",
+                "decimals": decimals,
+            }))
+        };
+        assert_eq!(kind(query(false)), json!(0));
+        assert_eq!(kind(query(true)), json!(7));
+        let count = json!({ "answerId": "1", "json": "3" });
+        assert_eq!(kind(json_iface.parse(&count, false)), json!(1));
+    }
+
+    #[test]
+    fn test_json_query() {
+        let json = ExactJson::parse(
+            r#"{"data": {"items": [{"id": 1, "name": "a"}, {"id": 2, "name": "b"}], "key-1": true}}"#,
+        )
+        .unwrap();
+        let query = |path: &str| json.query(path).unwrap().map(|v| v.to_string());
+        assert_eq!(query("$.data.items[1].name"), Some(r#""b""#.to_owned()));
+        assert_eq!(query("$.data['key-1']"), Some("true".to_owned()));
+        assert_eq!(query("$.data.items[*].id"), Some("[1,2]".to_owned()));
+        assert_eq!(query("$.data.items[5]"), None);
+        assert_eq!(query("$"), Some(json.to_string()));
+        assert!(json.query("data.items").is_err());
+        assert!(json.query("$.data[").is_err());
+    }
+
+    #[test]
+    fn test_typed_json() {
+        let kind = ParamType::Tuple(vec![
+            Param::new("name", ParamType::Bytes),
+            Param::new("amount", ParamType::Uint(128)),
+            Param::new("tags", ParamType::Array(Box::new(ParamType::String))),
+        ]);
+        let value = json!({
+            "name": hex::encode("Alice"),
+            "amount": "340282366920938463463374607431768211455",
+            "tags": ["x", "y"],
+        });
+        assert_eq!(
+            typed_json(&value, &kind).to_string(),
+            r#"{"name":"Alice","amount":340282366920938463463374607431768211455,"tags":["x","y"]}"#
+        );
+    }
 }
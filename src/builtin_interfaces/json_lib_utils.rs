@@ -1,10 +1,16 @@
-use crate::sdk_prelude::{deserialize_cell_from_base64, serialize_cell_to_base64};
+use crate::sdk_prelude::{
+    decode_abi_number, deserialize_cell_from_base64, serialize_cell_to_base64, slice_from_cell,
+};
+use serde::de::{Deserializer, MapAccess, Visitor};
 use serde_derive::{Deserialize, Serialize};
+use serde_json::value::RawValue;
 use serde_json::{json, Value as JsonValue};
 use serde_repr::{Deserialize_repr, Serialize_repr};
 use sha2::Digest;
 use std::collections::HashMap;
-use ton_abi::{contract::ABI_VERSION_2_0, token::Tokenizer, Param, ParamType, TokenValue};
+use std::fmt;
+use ton_abi::token::{Detokenizer, Tokenizer};
+use ton_abi::{contract::ABI_VERSION_2_0, Param, ParamType, TokenValue};
 
 #[derive(Serialize_repr, Deserialize_repr)]
 #[repr(u8)]
@@ -16,6 +22,9 @@ pub enum ValKind {
     Object = 4,
     Null = 5,
     Cell = 6,
+    /// Number which is not an integer or doesn't fit into int256.
+    /// Value is the number as written in JSON.
+    Decimal = 7,
 }
 
 impl Default for ValKind {
@@ -49,15 +58,24 @@ impl Value {
         Some(val)
     }
 
-    fn new_number(v: serde_json::Number) -> Option<Self> {
-        if v.is_i64() {
-            let mut val = Self::default();
-            val.kind = ValKind::Number;
-            val.value = Self::serialize(ParamType::Int(256), json!(v.as_i64()?))?;
-            Some(val)
-        } else {
-            Value::new_string(v.to_string())
+    /// Creates number from its JSON text. Integers which fit into int256 are packed
+    /// as `Number`. Other numbers are packed as `Decimal` if `exact` is set, otherwise
+    /// as `String`, which DeBots written before `Decimal` kind expect.
+    fn new_number(v: &str, exact: bool) -> Option<Self> {
+        let mut val = Self::default();
+        let is_integer = v.bytes().all(|c| c.is_ascii_digit() || c == b'-');
+        match Self::serialize(ParamType::Int(256), json!(v)).filter(|_| is_integer) {
+            Some(value) => {
+                val.kind = ValKind::Number;
+                val.value = value;
+            }
+            None if exact => {
+                val.kind = ValKind::Decimal;
+                val.value = Self::serialize(ParamType::Bytes, json!(hex::encode(v)))?;
+            }
+            None => return Self::new_string(v.to_owned()),
         }
+        Some(val)
     }
 
     fn new_string(v: String) -> Option<Self> {
@@ -74,13 +92,13 @@ impl Value {
         Some(val)
     }
 
-    fn new_object(map: serde_json::map::Map<String, JsonValue>) -> Option<Self> {
+    fn new_object(entries: Vec<(String, Value)>) -> Option<Self> {
         let mut val = Self {
             kind: ValKind::Object,
             ..Default::default()
         };
-        for (k, v) in map {
-            let json: JsonValue = serde_json::to_value(pack(v)?).ok()?;
+        for (k, v) in entries {
+            let json: JsonValue = serde_json::to_value(v).ok()?;
             let packed = Self::pack_value_to_cell(json, Some(&k))?;
             let mut hasher = sha2::Sha256::new();
             hasher.update(k);
@@ -91,13 +109,13 @@ impl Value {
         Some(val)
     }
 
-    fn new_array(array: Vec<JsonValue>) -> Option<Self> {
+    fn new_array(array: Vec<Value>) -> Option<Self> {
         let mut val = Self {
             kind: ValKind::Array,
             ..Default::default()
         };
         for element in array {
-            let json: JsonValue = serde_json::to_value(pack(element)?).ok()?;
+            let json: JsonValue = serde_json::to_value(element).ok()?;
             let packed = Self::pack_value_to_cell(json, None)?;
             val.array.push(Cell { cell: packed });
         }
@@ -105,6 +123,40 @@ impl Value {
     }
 
     fn pack_value_to_cell(mut json: JsonValue, key: Option<&String>) -> Option<String> {
+        let params = Self::cell_params(key.is_some());
+        if let Some(k) = key {
+            json["key"] = json!(hex::encode(k));
+        }
+
+        let tokens = Tokenizer::tokenize_all_params(&params, &json).unwrap();
+        let builder =
+            TokenValue::pack_values_into_chain(&tokens[..], vec![], &ABI_VERSION_2_0).unwrap();
+        let serialized =
+            serialize_cell_to_base64(&builder.into_cell().unwrap(), "QueryValue").ok()?;
+        Some(serialized)
+    }
+
+    /// Reverse of `pack_value_to_cell`. Returns value and its key if cell is an object entry.
+    fn unpack_value_from_cell(cell: &str, with_key: bool) -> Option<(Self, Option<String>)> {
+        let json = Self::decode_cell(&Self::cell_params(with_key), cell)?;
+        let key = match json.get("key") {
+            Some(key) => Some(String::from_utf8(hex::decode(key.as_str()?).ok()?).ok()?),
+            None => None,
+        };
+        Some((Self::from_abi_json(json)?, key))
+    }
+
+    /// Creates value from its ABI tuple representation decoded from cell or message.
+    pub(crate) fn from_abi_json(mut json: JsonValue) -> Option<Self> {
+        let kind = decode_abi_number::<u8>(json["kind"].as_str()?).ok()?;
+        json["kind"] = json!(kind);
+        if let Some(obj) = json.as_object_mut() {
+            obj.remove("key");
+        }
+        serde_json::from_value(json).ok()
+    }
+
+    fn cell_params(with_key: bool) -> Vec<Param> {
         let mut params = vec![
             Param::new("kind", ParamType::Uint(8)),
             Param::new("value", ParamType::Cell),
@@ -120,17 +172,10 @@ impl Value {
                 )]))),
             ),
         ];
-        if let Some(k) = key {
+        if with_key {
             params.push(Param::new("key", ParamType::Bytes));
-            json["key"] = json!(hex::encode(k));
         }
-
-        let tokens = Tokenizer::tokenize_all_params(&params, &json).unwrap();
-        let builder =
-            TokenValue::pack_values_into_chain(&tokens[..], vec![], &ABI_VERSION_2_0).unwrap();
-        let serialized =
-            serialize_cell_to_base64(&builder.into_cell().unwrap(), "QueryValue").ok()?;
-        Some(serialized)
+        params
     }
 
     fn serialize(param_type: ParamType, json: JsonValue) -> Option<String> {
@@ -143,6 +188,23 @@ impl Value {
             TokenValue::pack_values_into_chain(&tokens[..], vec![], &ABI_VERSION_2_0).ok()?;
         serialize_cell_to_base64(&builder.into_cell().unwrap(), "QueryValue").ok()
     }
+
+    fn deserialize_param(param_type: ParamType, cell: &str) -> Option<JsonValue> {
+        let mut json = Self::decode_cell(&[Param::new("arg0", param_type)], cell)?;
+        Some(json["arg0"].take())
+    }
+
+    fn decode_cell(params: &[Param], cell: &str) -> Option<JsonValue> {
+        let (_, cell) = deserialize_cell_from_base64(cell, "QueryValue").ok()?;
+        let slice = slice_from_cell(cell).ok()?;
+        let tokens = TokenValue::decode_params(params, slice, &ABI_VERSION_2_0, false).ok()?;
+        Detokenizer::detokenize_to_json_value(&tokens).ok()
+    }
+
+    fn deserialize_text(cell: &str) -> Option<String> {
+        let bytes = Self::deserialize_param(ParamType::Bytes, cell)?;
+        String::from_utf8(hex::decode(bytes.as_str()?).ok()?).ok()
+    }
 }
 
 pub fn pack(json_obj: JsonValue) -> Option<Value> {
@@ -150,9 +212,338 @@ pub fn pack(json_obj: JsonValue) -> Option<Value> {
         JsonValue::Null => Some(Value::new_null()),
         JsonValue::Bool(v) => Value::new_bool(v),
         JsonValue::String(v) => Value::new_string(v),
-        JsonValue::Number(v) => Value::new_number(v),
-        JsonValue::Object(map) => Value::new_object(map),
-        JsonValue::Array(array) => Value::new_array(array),
+        JsonValue::Number(v) => Value::new_number(&v.to_string(), false),
+        JsonValue::Object(map) => Value::new_object(
+            map.into_iter()
+                .map(|(k, v)| Some((k, pack(v)?)))
+                .collect::<Option<_>>()?,
+        ),
+        JsonValue::Array(array) => {
+            Value::new_array(array.into_iter().map(pack).collect::<Option<_>>()?)
+        }
+    }
+}
+
+/// Reverse of `pack`. Numbers which don't fit into `serde_json::Number` lose precision.
+pub fn unpack(value: Value) -> Option<JsonValue> {
+    serde_json::from_str(&unpack_exact(value)?.to_string()).ok()
+}
+
+/// Reverse of `pack_exact`. Object keys are sorted, entries without a key are named
+/// `#<key hash>`.
+pub(crate) fn unpack_exact(value: Value) -> Option<ExactJson> {
+    Some(match value.kind {
+        ValKind::Null => ExactJson::Null,
+        ValKind::Bool => {
+            ExactJson::Bool(Value::deserialize_param(ParamType::Bool, &value.value)?.as_bool()?)
+        }
+        ValKind::Number => match Value::deserialize_param(ParamType::Int(256), &value.value)? {
+            JsonValue::String(v) => ExactJson::Number(v),
+            v => ExactJson::Number(v.to_string()),
+        },
+        ValKind::Decimal => ExactJson::Number(Value::deserialize_text(&value.value)?),
+        ValKind::String => ExactJson::String(Value::deserialize_text(&value.value)?),
+        ValKind::Cell => ExactJson::String(value.value),
+        ValKind::Object => {
+            let mut entries = value
+                .object
+                .iter()
+                .map(|(hash, cell)| {
+                    // Entries packed without `key` are named by their key hash.
+                    let (entry, key) = Value::unpack_value_from_cell(cell, true)
+                        .or_else(|| Value::unpack_value_from_cell(cell, false))?;
+                    let key = key.unwrap_or_else(|| format!("#{}", hash.trim_start_matches("0x")));
+                    Some((key, unpack_exact(entry)?))
+                })
+                .collect::<Option<Vec<_>>>()?;
+            entries.sort_by(|a, b| a.0.cmp(&b.0));
+            ExactJson::Object(entries)
+        }
+        ValKind::Array => ExactJson::Array(
+            value
+                .array
+                .iter()
+                .map(|c| unpack_exact(Value::unpack_value_from_cell(&c.cell, false)?.0))
+                .collect::<Option<_>>()?,
+        ),
+    })
+}
+
+/// Same as `pack`, but integers are packed exactly as written in JSON.
+/// Other numbers are packed as `Decimal` only if `decimals` is set.
+pub(crate) fn pack_exact(json_obj: ExactJson, decimals: bool) -> Option<Value> {
+    match json_obj {
+        ExactJson::Null => Some(Value::new_null()),
+        ExactJson::Bool(v) => Value::new_bool(v),
+        ExactJson::String(v) => Value::new_string(v),
+        ExactJson::Number(v) => Value::new_number(&v, decimals),
+        ExactJson::Object(entries) => Value::new_object(
+            entries
+                .into_iter()
+                .map(|(k, v)| Some((k, pack_exact(v, decimals)?)))
+                .collect::<Option<_>>()?,
+        ),
+        ExactJson::Array(array) => Value::new_array(
+            array
+                .into_iter()
+                .map(|v| pack_exact(v, decimals))
+                .collect::<Option<_>>()?,
+        ),
+    }
+}
+
+/// JSON value with numbers kept as text, so big integers and decimals are not rounded.
+/// Object entries are kept in source order, including duplicate keys. Order is lost
+/// when value is packed, `unpack_exact` returns keys sorted.
+#[derive(Clone, Debug, PartialEq)]
+pub(crate) enum ExactJson {
+    Null,
+    Bool(bool),
+    Number(String),
+    String(String),
+    Array(Vec<ExactJson>),
+    Object(Vec<(String, ExactJson)>),
+}
+
+enum PathSegment {
+    Key(String),
+    Index(usize),
+    Wildcard,
+}
+
+impl ExactJson {
+    pub fn parse(json: &str) -> Result<Self, String> {
+        let raw: &RawValue = serde_json::from_str(json).map_err(|e| e.to_string())?;
+        Self::from_raw(raw).map_err(|e| e.to_string())
+    }
+
+    fn from_raw(raw: &RawValue) -> serde_json::Result<Self> {
+        let text = raw.get().trim();
+        Ok(match text.as_bytes().first() {
+            Some(b'{') => {
+                let entries: RawEntries = serde_json::from_str(text)?;
+                ExactJson::Object(
+                    entries
+                        .0
+                        .into_iter()
+                        .map(|(k, v)| Ok((k, Self::from_raw(v)?)))
+                        .collect::<serde_json::Result<_>>()?,
+                )
+            }
+            Some(b'[') => {
+                let array: Vec<&RawValue> = serde_json::from_str(text)?;
+                ExactJson::Array(
+                    array
+                        .into_iter()
+                        .map(Self::from_raw)
+                        .collect::<serde_json::Result<_>>()?,
+                )
+            }
+            Some(b'"') => ExactJson::String(serde_json::from_str(text)?),
+            Some(b't') | Some(b'f') => ExactJson::Bool(serde_json::from_str(text)?),
+            Some(b'n') => ExactJson::Null,
+            _ => ExactJson::Number(text.to_owned()),
+        })
+    }
+
+    /// Selects values by JSONPath. Supported subset: `$`, `.key`, `['key']`,
+    /// `[index]`, `.*` and `[*]`. Path with wildcards returns array of all matches,
+    /// otherwise the single match. Returns `None` if nothing matches.
+    pub fn query(&self, path: &str) -> Result<Option<ExactJson>, String> {
+        let segments = parse_path(path)?;
+        let mut current = vec![self];
+        for segment in &segments {
+            current = current
+                .into_iter()
+                .flat_map(|value| -> Vec<&ExactJson> {
+                    match (segment, value) {
+                        // The last of duplicate keys wins, as in JavaScript.
+                        (PathSegment::Key(key), ExactJson::Object(entries)) => entries
+                            .iter()
+                            .rev()
+                            .find(|(k, _)| k == key)
+                            .map(|(_, v)| v)
+                            .into_iter()
+                            .collect(),
+                        (PathSegment::Index(i), ExactJson::Array(items)) => {
+                            items.get(*i).into_iter().collect()
+                        }
+                        (PathSegment::Wildcard, ExactJson::Array(items)) => items.iter().collect(),
+                        (PathSegment::Wildcard, ExactJson::Object(entries)) => {
+                            entries.iter().map(|(_, v)| v).collect()
+                        }
+                        _ => vec![],
+                    }
+                })
+                .collect();
+        }
+        if segments.iter().any(|s| matches!(s, PathSegment::Wildcard)) {
+            Ok(Some(ExactJson::Array(current.into_iter().cloned().collect())))
+        } else {
+            Ok(current.into_iter().next().cloned())
+        }
+    }
+}
+
+impl fmt::Display for ExactJson {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ExactJson::Null => write!(f, "null"),
+            ExactJson::Bool(v) => write!(f, "{v}"),
+            ExactJson::Number(v) => write!(f, "{v}"),
+            ExactJson::String(v) => write_json_str(f, v),
+            ExactJson::Array(items) => {
+                write!(f, "[")?;
+                for (i, item) in items.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ",")?;
+                    }
+                    write!(f, "{item}")?;
+                }
+                write!(f, "]")
+            }
+            ExactJson::Object(entries) => {
+                write!(f, "{{")?;
+                for (i, (key, value)) in entries.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ",")?;
+                    }
+                    write_json_str(f, key)?;
+                    write!(f, ":{value}")?;
+                }
+                write!(f, "}}")
+            }
+        }
+    }
+}
+
+/// Object entries in source order. Unlike maps, duplicate keys are kept.
+struct RawEntries<'a>(Vec<(String, &'a RawValue)>);
+
+impl<'de> serde::Deserialize<'de> for RawEntries<'de> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct EntriesVisitor;
+
+        impl<'de> Visitor<'de> for EntriesVisitor {
+            type Value = RawEntries<'de>;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "JSON object")
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+                let mut entries = vec![];
+                while let Some(entry) = map.next_entry()? {
+                    entries.push(entry);
+                }
+                Ok(RawEntries(entries))
+            }
+        }
+
+        deserializer.deserialize_map(EntriesVisitor)
+    }
+}
+
+fn write_json_str(f: &mut fmt::Formatter<'_>, s: &str) -> fmt::Result {
+    write!(f, "{}", serde_json::to_string(s).map_err(|_| fmt::Error)?)
+}
+
+fn parse_path(path: &str) -> Result<Vec<PathSegment>, String> {
+    let err = || format!("invalid json path \"{path}\"");
+    let mut rest = path.trim().strip_prefix('$').ok_or_else(err)?;
+    let mut segments = vec![];
+    while !rest.is_empty() {
+        if let Some(r) = rest.strip_prefix('.') {
+            let end = r.find(['.', '[']).unwrap_or(r.len());
+            let key = &r[..end];
+            segments.push(match key {
+                "" => return Err(err()),
+                "*" => PathSegment::Wildcard,
+                _ => PathSegment::Key(key.to_owned()),
+            });
+            rest = &r[end..];
+        } else if let Some(r) = rest.strip_prefix('[') {
+            let (segment, end) = match r.chars().next() {
+                Some(quote @ ('\'' | '"')) => {
+                    let len = r[1..].find(quote).ok_or_else(err)?;
+                    (PathSegment::Key(r[1..=len].to_owned()), len + 2)
+                }
+                Some('*') => (PathSegment::Wildcard, 1),
+                _ => {
+                    let end = r.find(']').ok_or_else(err)?;
+                    (PathSegment::Index(r[..end].parse().map_err(|_| err())?), end)
+                }
+            };
+            segments.push(segment);
+            rest = r[end..].strip_prefix(']').ok_or_else(err)?;
+        } else {
+            return Err(err());
+        }
+    }
+    Ok(segments)
+}
+
+/// Converts detokenized ABI value to JSON: reverse of `bypass_json`.
+/// `bytes` are converted to strings, integers to JSON numbers.
+pub(crate) fn typed_json(value: &JsonValue, kind: &ParamType) -> ExactJson {
+    match kind {
+        ParamType::Uint(_)
+        | ParamType::Int(_)
+        | ParamType::VarUint(_)
+        | ParamType::VarInt(_)
+        | ParamType::Token
+        | ParamType::Time
+        | ParamType::Expire => match value {
+            JsonValue::String(v) if !v.starts_with("0x") => ExactJson::Number(v.clone()),
+            JsonValue::Number(v) => ExactJson::Number(v.to_string()),
+            _ => untyped_json(value),
+        },
+        ParamType::Bytes | ParamType::FixedBytes(_) => {
+            let hex_str = value.as_str().unwrap_or_default();
+            match hex::decode(hex_str).ok().and_then(|b| String::from_utf8(b).ok()) {
+                Some(v) => ExactJson::String(v),
+                None => ExactJson::String(hex_str.to_owned()),
+            }
+        }
+        ParamType::Tuple(params) => ExactJson::Object(
+            params
+                .iter()
+                .map(|p| (p.name.clone(), typed_json(&value[&p.name], &p.kind)))
+                .collect(),
+        ),
+        ParamType::Array(elem) | ParamType::FixedArray(elem, _) => ExactJson::Array(
+            value
+                .as_array()
+                .map(|items| items.iter().map(|v| typed_json(v, elem)).collect())
+                .unwrap_or_default(),
+        ),
+        ParamType::Map(_, elem) => ExactJson::Object(
+            value
+                .as_object()
+                .map(|map| {
+                    map.iter()
+                        .map(|(k, v)| (k.clone(), typed_json(v, elem)))
+                        .collect()
+                })
+                .unwrap_or_default(),
+        ),
+        ParamType::Optional(inner) if !value.is_null() => typed_json(value, inner),
+        _ => untyped_json(value),
+    }
+}
+
+fn untyped_json(value: &JsonValue) -> ExactJson {
+    match value {
+        JsonValue::Null => ExactJson::Null,
+        JsonValue::Bool(v) => ExactJson::Bool(*v),
+        JsonValue::Number(v) => ExactJson::Number(v.to_string()),
+        JsonValue::String(v) => ExactJson::String(v.clone()),
+        JsonValue::Array(items) => ExactJson::Array(items.iter().map(untyped_json).collect()),
+        JsonValue::Object(map) => ExactJson::Object(
+            map.iter()
+                .map(|(k, v)| (k.clone(), untyped_json(v)))
+                .collect(),
+        ),
     }
 }
 
@@ -240,3 +631,77 @@ pub(crate) fn bypass_json(
     }
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{pack, pack_exact, unpack, unpack_exact, ExactJson, ValKind, Value};
+    use proptest::prelude::*;
+    use serde_json::{json, Value as JsonValue};
+
+    fn arb_json() -> impl Strategy<Value = JsonValue> {
+        let leaf = prop_oneof![
+            Just(JsonValue::Null),
+            any::<bool>().prop_map(JsonValue::from),
+            any::<i64>().prop_map(JsonValue::from),
+            any::<u64>().prop_map(JsonValue::from),
+            "[ -~а-яА-ЯёЁ一-龥]{0,16}".prop_map(JsonValue::from),
+            "te6cc[A-Za-z0-9+/]{0,16}={0,2}".prop_map(JsonValue::from),
+            Just(JsonValue::from("te6ccgEBAQEAAgAAAA==")),
+        ];
+        leaf.prop_recursive(3, 24, 4, |inner| {
+            prop_oneof![
+                prop::collection::vec(inner.clone(), 0..4).prop_map(JsonValue::from),
+                prop::collection::btree_map("[ -~а-яА-Я一-龥]{0,8}", inner, 0..4)
+                    .prop_map(|map| JsonValue::Object(map.into_iter().collect())),
+            ]
+        })
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(64))]
+
+        #[test]
+        fn test_pack_roundtrip(json in arb_json()) {
+            prop_assert_eq!(unpack(pack(json.clone()).unwrap()), Some(json));
+        }
+    }
+
+    #[test]
+    fn test_pack_exact_roundtrip() {
+        let text = r#"{"big":-123456789012345678901234567890123,"empty":{},"list":[0.10,1e-7,"",null,true],"nested":{"a b":[[]]}}"#;
+        let json = ExactJson::parse(text).unwrap();
+        let unpacked = unpack_exact(pack_exact(json.clone(), true).unwrap()).unwrap();
+        assert_eq!(unpacked, json);
+        assert_eq!(unpacked.to_string(), text);
+    }
+
+    #[test]
+    fn test_pack_float_as_string() {
+        let packed = pack(json!(0.5)).unwrap();
+        assert!(matches!(packed.kind, ValKind::String));
+        assert_eq!(unpack(packed), Some(json!("0.5")));
+        let json = ExactJson::parse("0.10").unwrap();
+        assert!(matches!(pack_exact(json.clone(), false).unwrap().kind, ValKind::String));
+        assert!(matches!(pack_exact(json, true).unwrap().kind, ValKind::Decimal));
+    }
+
+    #[test]
+    fn test_unpack_keyless_entry() {
+        let entry = serde_json::to_value(pack(json!(true)).unwrap()).unwrap();
+        let hash = "ab".repeat(32);
+        let mut value = pack(json!({ "a": 1 })).unwrap();
+        value.object.insert(
+            format!("0x{hash}"),
+            Value::pack_value_to_cell(entry, None).unwrap(),
+        );
+        let placeholder = format!("#{hash}");
+        assert_eq!(unpack(value), Some(json!({ "a": 1, placeholder: true })));
+    }
+
+    #[test]
+    fn test_unpack_cell_string() {
+        let cell = "te6ccgEBAQEAAgAAAA==";
+        let unpacked = unpack(pack(json!({ "c": cell })).unwrap()).unwrap();
+        assert_eq!(unpacked, json!({ "c": cell }));
+    }
+}
@@ -0,0 +1,204 @@
+use super::dinterface::{decode_answer_id, get_arg, DebotInterface, InterfaceResult};
+use crate::browser::BrowserRef;
+use crate::sdk_prelude::*;
+use serde_json::{json, Value};
+
+const ABI: &str = r#"
+{
+	"ABI version": 2,
+	"version": "2.2",
+	"header": ["time"],
+	"functions": [
+		{
+			"name": "output",
+			"inputs": [
+				{"name":"answerId","type":"uint32"},
+				{"name":"prompt","type":"string"},
+				{"name":"data","type":"string"}
+			],
+			"outputs": [
+				{"name":"result","type":"uint8"}
+			]
+		},
+		{
+			"name": "getSupportDataType",
+			"inputs": [
+				{"name":"answerId","type":"uint32"},
+				{"name":"data","type":"string"}
+			],
+			"outputs": [
+				{"name":"result","type":"uint8"}
+			]
+		},
+		{
+			"name": "constructor",
+			"inputs": [
+			],
+			"outputs": [
+			]
+		}
+	],
+	"data": [
+	],
+	"events": [
+	],
+	"fields": [
+		{"name":"_pubkey","type":"uint256"},
+		{"name":"_timestamp","type":"uint64"},
+		{"name":"_constructorFlag","type":"bool"}
+	]
+}
+"#;
+
+const MEDIA_ID: &str = "d357175cfe8978d39b0f86552dcd9404973493e2816101d9ff6c05ee248ef6bd";
+
+const SUPPORTED_MEDIA_TYPES: [&str; 5] = [
+    "image/png",
+    "image/jpeg",
+    "image/gif",
+    "image/webp",
+    "image/svg+xml",
+];
+
+// Maximal size of decoded media content.
+const MAX_MEDIA_SIZE: usize = 5 * 1024 * 1024;
+
+#[derive(Debug, PartialEq)]
+enum MediaStatus {
+    Success = 0,
+    UnsupportedMediaType = 1,
+    InvalidDataScheme = 2,
+    TooLarge = 3,
+}
+
+pub struct MediaInterface {
+    browser: BrowserRef,
+}
+
+impl MediaInterface {
+    pub fn new(browser: BrowserRef) -> Self {
+        Self { browser }
+    }
+
+    async fn output(&self, args: &Value) -> InterfaceResult {
+        let answer_id = decode_answer_id(args)?;
+        let prompt = get_arg(args, "prompt")?;
+        let data = get_arg(args, "data")?;
+        let status = match normalize_data_url(&data) {
+            Ok(data) => {
+                self.browser
+                    .output_media(prompt, data)
+                    .await
+                    .map_err(|e| format!("{e}"))?;
+                MediaStatus::Success
+            }
+            Err(status) => status,
+        };
+        Ok((answer_id, json!({ "result": status as u8 })))
+    }
+
+    fn get_support_data_type(&self, args: &Value) -> InterfaceResult {
+        let answer_id = decode_answer_id(args)?;
+        let data = get_arg(args, "data")?;
+        let status = match normalize_data_url(&data) {
+            Ok(_) => MediaStatus::Success,
+            Err(status) => status,
+        };
+        Ok((answer_id, json!({ "result": status as u8 })))
+    }
+}
+
+/// Checks that `data` is a data URL with base64 payload of supported media type
+/// and returns it in canonical form: `data:<mime>;base64,<payload>`.
+fn normalize_data_url(data: &str) -> Result<String, MediaStatus> {
+    let rest = data
+        .trim()
+        .strip_prefix("data:")
+        .ok_or(MediaStatus::InvalidDataScheme)?;
+    let (meta, payload) = rest
+        .split_once(',')
+        .ok_or(MediaStatus::InvalidDataScheme)?;
+    let mut meta = meta.split(';');
+    let mime = meta.next().unwrap_or_default().trim().to_lowercase();
+    if !meta.any(|param| param.trim().eq_ignore_ascii_case("base64")) {
+        return Err(MediaStatus::InvalidDataScheme);
+    }
+    if !SUPPORTED_MEDIA_TYPES.contains(&mime.as_str()) {
+        return Err(MediaStatus::UnsupportedMediaType);
+    }
+    let payload: String = payload.chars().filter(|c| !c.is_whitespace()).collect();
+    // Encoded size is 4/3 of decoded size, check it before decoding.
+    if payload.len() / 4 * 3 > MAX_MEDIA_SIZE {
+        return Err(MediaStatus::TooLarge);
+    }
+    let content = base64::decode(&payload).map_err(|_| MediaStatus::InvalidDataScheme)?;
+    if content.is_empty() {
+        return Err(MediaStatus::InvalidDataScheme);
+    }
+    if content.len() > MAX_MEDIA_SIZE {
+        return Err(MediaStatus::TooLarge);
+    }
+    Ok(format!("data:{mime};base64,{}", base64::encode(&content)))
+}
+
+#[async_trait::async_trait]
+impl DebotInterface for MediaInterface {
+    fn get_id(&self) -> String {
+        MEDIA_ID.to_string()
+    }
+
+    fn get_abi(&self) -> Abi {
+        Abi::Json(ABI.to_owned())
+    }
+
+    async fn call(&self, func: &str, args: &Value) -> InterfaceResult {
+        match func {
+            "output" => self.output(args).await,
+            "getSupportDataType" => self.get_support_data_type(args),
+            _ => Err(format!("function \"{func}\" is not implemented")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_data_url() {
+        assert_eq!(
+            normalize_data_url(" data:Image/PNG;base64,iVBO Rw0K\n").unwrap(),
+            "data:image/png;base64,iVBORw0K"
+        );
+        assert_eq!(
+            normalize_data_url("data:image/svg+xml;charset=utf-8;base64,PHN2Zy8+").unwrap(),
+            "data:image/svg+xml;base64,PHN2Zy8+"
+        );
+    }
+
+    #[test]
+    fn test_normalize_data_url_errors() {
+        let cases = [
+            ("image/png;base64,iVBORw0K", MediaStatus::InvalidDataScheme),
+            ("data:image/png;base64", MediaStatus::InvalidDataScheme),
+            ("data:image/png,iVBORw0K", MediaStatus::InvalidDataScheme),
+            ("data:image/png;base64,iVBO!w0K", MediaStatus::InvalidDataScheme),
+            ("data:image/png;base64,", MediaStatus::InvalidDataScheme),
+            ("data:text/html;base64,PGI+", MediaStatus::UnsupportedMediaType),
+        ];
+        for (data, status) in cases {
+            assert_eq!(normalize_data_url(data), Err(status), "{data}");
+        }
+    }
+
+    #[test]
+    fn test_normalize_data_url_too_large() {
+        let max = base64::encode(vec![0u8; MAX_MEDIA_SIZE / 3 * 3]);
+        assert!(normalize_data_url(&format!("data:image/gif;base64,{max}")).is_ok());
+        let over = base64::encode(vec![0u8; MAX_MEDIA_SIZE + 3]);
+        assert_eq!(
+            normalize_data_url(&format!("data:image/gif;base64,{over}")),
+            Err(MediaStatus::TooLarge)
+        );
+    }
+}
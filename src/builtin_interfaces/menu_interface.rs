@@ -0,0 +1,105 @@
+use super::dinterface::{get_arg, get_num_arg, DebotInterface, InterfaceResult};
+use crate::browser::{BrowserRef, MenuItem};
+use crate::sdk_prelude::*;
+use serde_json::{json, Value};
+
+const ABI: &str = r#"
+{
+	"ABI version": 2,
+	"version": "2.2",
+	"header": ["time"],
+	"functions": [
+		{
+			"name": "select",
+			"inputs": [
+				{"name":"title","type":"string"},
+				{"name":"description","type":"string"},
+				{"components":[{"name":"title","type":"string"},{"name":"description","type":"string"},{"name":"handlerId","type":"uint32"}],"name":"items","type":"tuple[]"}
+			],
+			"outputs": [
+				{"name":"index","type":"uint32"}
+			]
+		},
+		{
+			"name": "constructor",
+			"inputs": [
+			],
+			"outputs": [
+			]
+		}
+	],
+	"data": [
+	],
+	"events": [
+	],
+	"fields": [
+		{"name":"_pubkey","type":"uint256"},
+		{"name":"_timestamp","type":"uint64"},
+		{"name":"_constructorFlag","type":"bool"}
+	]
+}
+"#;
+
+const MENU_ID: &str = "ac1a4d3ecea232e49783df4a23a81823cdca3205dc58cd20c4db259c25605b48";
+
+pub struct MenuInterface {
+    browser: BrowserRef,
+}
+
+impl MenuInterface {
+    pub fn new(browser: BrowserRef) -> Self {
+        Self { browser }
+    }
+
+    /// Shows menu to user. Selected item index is returned to the item handler.
+    async fn select(&self, args: &Value) -> InterfaceResult {
+        let title = get_arg(args, "title")?;
+        let description = get_arg(args, "description")?;
+        let items = decode_items(args)?;
+        if items.is_empty() {
+            return Err("menu has no items".to_string());
+        }
+        let index = self
+            .browser
+            .select_menu(title, description, items.clone())
+            .await
+            .map_err(|e| format!("{e}"))?;
+        let item = items
+            .get(index as usize)
+            .ok_or_else(|| format!("invalid menu item index: {index}"))?;
+        Ok((item.handler_id, json!({ "index": index })))
+    }
+}
+
+fn decode_items(args: &Value) -> Result<Vec<MenuItem>, String> {
+    let array = args["items"]
+        .as_array()
+        .ok_or_else(|| "\"items\" is invalid: must be array".to_string())?;
+    let mut items = vec![];
+    for item in array {
+        items.push(MenuItem {
+            title: get_arg(item, "title")?,
+            description: get_arg(item, "description")?,
+            handler_id: get_num_arg::<u32>(item, "handlerId")?,
+        });
+    }
+    Ok(items)
+}
+
+#[async_trait::async_trait]
+impl DebotInterface for MenuInterface {
+    fn get_id(&self) -> String {
+        MENU_ID.to_string()
+    }
+
+    fn get_abi(&self) -> Abi {
+        Abi::Json(ABI.to_owned())
+    }
+
+    async fn call(&self, func: &str, args: &Value) -> InterfaceResult {
+        match func {
+            "select" => self.select(args).await,
+            _ => Err(format!("function \"{func}\" is not implemented")),
+        }
+    }
+}
@@ -1,18 +1,61 @@
+mod abi_interface;
+mod address_input_interface;
+mod address_interface;
+mod amount_input_interface;
 mod base64_interface;
+mod boc_interface;
+mod confirm_input_interface;
+mod crypto_interface;
+mod datetime_input_interface;
+mod encryption_box_input_interface;
 mod dinterface;
 mod hex_interface;
 mod json_interface;
 mod json_lib_utils;
+mod media_interface;
+mod menu_interface;
 mod msg_interface;
 mod network_interface;
+mod network_v2_interface;
+mod number_input_interface;
+mod qrcode_interface;
 mod query_interface;
+mod query_v2_interface;
 mod sdk_interface;
+mod signing_box_input_interface;
+mod storage_interface;
+mod strings_interface;
+mod terminal_interface;
+mod timer_interface;
+mod user_info_interface;
 
+pub(crate) use abi_interface::AbiInterface;
+pub(crate) use address_input_interface::AddressInputInterface;
+pub(crate) use address_interface::AddressInterface;
+pub(crate) use amount_input_interface::AmountInputInterface;
 pub(crate) use base64_interface::Base64Interface;
+pub(crate) use boc_interface::BocInterface;
+pub(crate) use confirm_input_interface::ConfirmInputInterface;
+pub(crate) use crypto_interface::CryptoInterface;
+pub(crate) use datetime_input_interface::DateTimeInputInterface;
+pub(crate) use encryption_box_input_interface::EncryptionBoxInputInterface;
 pub use dinterface::*;
 pub(crate) use hex_interface::HexInterface;
 pub(crate) use json_interface::JsonInterface;
+pub(crate) use media_interface::MediaInterface;
+pub(crate) use menu_interface::MenuInterface;
 pub(crate) use msg_interface::MsgInterface;
 pub(crate) use network_interface::NetworkInterface;
+pub(crate) use network_v2_interface::NetworkV2Interface;
+pub(crate) use number_input_interface::NumberInputInterface;
+pub(crate) use qrcode_interface::QRCodeInterface;
 pub(crate) use query_interface::QueryInterface;
+pub(crate) use query_v2_interface::{encode_event, QueryV2Interface};
 pub(crate) use sdk_interface::SdkInterface;
+pub(crate) use signing_box_input_interface::SigningBoxInputInterface;
+pub(crate) use storage_interface::StorageInterface;
+pub(crate) use strings_interface::StringsInterface;
+pub(crate) use terminal_interface::TerminalInterface;
+pub(crate) use timer_interface::TimerInterface;
+pub(crate) use user_info_interface::UserInfoInterface;
+pub use user_info_interface::UserProfile;
@@ -1,5 +1,7 @@
+use crate::boxes::BoxRegistry;
 use crate::common::*;
 use crate::sdk_prelude::*;
+use crate::wallet::{WalletCall, WalletConfig};
 use serde_json::Value;
 use ton_abi::Contract;
 
@@ -30,6 +32,17 @@ const ABI: &str = r#"
 			"outputs": [
 				{"name":"id","type":"uint256"}
 			]
+		},
+		{
+			"name": "sendFromWallet",
+			"inputs": [
+				{"name":"answerId","type":"uint32"},
+				{"name":"message","type":"cell"}
+			],
+			"outputs": [
+				{"name":"result","type":"uint32"},
+				{"name":"id","type":"uint256"}
+			]
 		}
 	]
 }
@@ -42,6 +55,8 @@ pub struct MsgInterface {
     debot_addr: String,
     debot_abi: Abi,
     browser: BrowserRef,
+    wallet: Option<WalletConfig>,
+    boxes: Arc<BoxRegistry>,
 }
 
 impl MsgInterface {
@@ -50,12 +65,16 @@ impl MsgInterface {
         debot_addr: String,
         debot_abi: Abi,
         browser: BrowserRef,
+        wallet: Option<WalletConfig>,
+        boxes: Arc<BoxRegistry>,
     ) -> Self {
         Self {
             ton,
             debot_addr,
             debot_abi,
             browser,
+            wallet,
+            boxes,
         }
     }
 
@@ -70,6 +89,14 @@ impl MsgInterface {
             .await
             .map_err(|e| format!("{e}"))?
             .handle;
+        self.boxes.add_signing_box(&signing_box);
+        let result = self.send_signed(message, signing_box.clone()).await;
+        // signing box is created for this call only.
+        self.boxes.release_signing_box(&signing_box);
+        result
+    }
+
+    async fn send_signed(&self, message: String, signing_box: SigningBoxHandle) -> InterfaceResult {
         let parsed_msg = parse_message(
             self.ton.clone(),
             ParamsOfParse {
@@ -93,6 +120,7 @@ impl MsgInterface {
             target_state,
             self.debot_addr.clone(),
             false,
+            self.boxes.clone(),
         )
         .await
         .map_err(|e| format!("{e}"))?;
@@ -139,6 +167,7 @@ impl MsgInterface {
             target_state,
             self.debot_addr.clone(),
             false,
+            self.boxes.clone(),
         )
         .await
         .map_err(|e| format!("{e}"))?;
@@ -161,6 +190,36 @@ impl MsgInterface {
             .get_input_id();
         Ok((answer_id, result.value.unwrap_or_default()))
     }
+
+    async fn send_from_wallet(&self, args: &Value) -> InterfaceResult {
+        let answer_id = decode_answer_id(args)?;
+        let message = get_arg(args, "message")?;
+        let result = match self.wallet.clone() {
+            Some(wallet) => {
+                match WalletCall::new(
+                    self.browser.clone(),
+                    self.ton.clone(),
+                    wallet,
+                    message,
+                    self.boxes.clone(),
+                )
+                .await
+                {
+                    Ok(call) => call.execute().await,
+                    Err(e) => Err(e),
+                }
+            }
+            None => Err(Error::wallet_not_configured()),
+        };
+        let (result, id) = match result {
+            Ok(id) => (0, format!("0x{id}")),
+            Err(e) => {
+                debug!(self.browser, "sendFromWallet failed: {}", e);
+                (e.code, "0".to_owned())
+            }
+        };
+        Ok((answer_id, json!({ "result": result, "id": id })))
+    }
 }
 
 #[async_trait::async_trait]
@@ -177,6 +236,7 @@ impl DebotInterface for MsgInterface {
         match func {
             "sendWithKeypair" => self.send_with_keypair(args).await,
             "sendAsync" => self.send_async(args).await,
+            "sendFromWallet" => self.send_from_wallet(args).await,
             _ => Err(format!("function \"{func}\" is not implemented")),
         }
     }
@@ -1,9 +1,12 @@
 use super::dinterface::{
     decode_answer_id, get_arg, get_array_strings, DebotInterface, InterfaceResult,
 };
+use super::network_v2_interface::{STATUS_ACCESS_DENIED, STATUS_RESPONSE_TOO_LARGE};
 use serde_json::{json, Value};
+use std::sync::Arc;
 use ton_client::abi::Abi;
 use crate::browser::{BrowserRef, FetchHeader};
+use crate::network_policy::NetworkPolicy;
 
 const ABI: &str = r#"
 {
@@ -48,11 +51,12 @@ const ID: &str = "e38aed5884dc3e4426a87c083faaf4fa08109189fbc0c79281112f52e062d8
 
 pub struct NetworkInterface {
     browser: BrowserRef,
+    policy: Arc<NetworkPolicy>,
 }
 
 impl NetworkInterface {
-    pub fn new(browser: BrowserRef) -> Self {
-        Self {browser}
+    pub fn new(browser: BrowserRef, policy: Arc<NetworkPolicy>) -> Self {
+        Self {browser, policy}
     }
 
     async fn post(&self, args: &Value) -> InterfaceResult {
@@ -78,39 +82,57 @@ impl NetworkInterface {
         headers: Vec<String>,
         body: Option<String>,
     ) -> Result<Value, String> {
-        let mut header_map = vec![];
-        for h in headers {
-            let mut iter = h.split(':');
-            let key = iter.next();
-            let value = iter.next();
-            if key.is_some() && value.is_some() {
-                header_map.push(FetchHeader {
-                    key: key.unwrap().trim().to_owned(),
-                    value: value.unwrap().trim().to_owned(),
-                });
-            }
+        if let Err(e) = self.policy.check_url(&url) {
+            return Ok(denied(STATUS_ACCESS_DENIED, e));
         }
         let response = self.browser.fetch(
             url,
             { if body.is_some() { "POST" } else { "GET" } }.to_string(),
-            header_map,
-            body
+            parse_headers(headers),
+            body,
         )
         .await
         .map_err(|e| format!("{e}"))?;
-
-        let mut ret_headers: Vec<String> = vec![];
-        for h in response.headers.iter() {
-            ret_headers.push(format!("{}: {}", h.key, h.value));
+        if let Err(e) = self.policy.check_response_size(response.content.len()) {
+            return Ok(denied(STATUS_RESPONSE_TOO_LARGE, e));
         }
+
         Ok(json!({
             "statusCode": response.status,
-            "retHeaders": ret_headers,
+            "retHeaders": format_headers(&response.headers),
             "content": response.content.clone(),
         }))
     }
 }
 
+/// Answer for request rejected by network policy. Reason is returned in content.
+fn denied(status: i32, reason: String) -> Value {
+    json!({
+        "statusCode": status,
+        "retHeaders": [],
+        "content": reason,
+    })
+}
+
+/// Parses headers in "Key: Value" form. Value can contain colons.
+pub(super) fn parse_headers(headers: Vec<String>) -> Vec<FetchHeader> {
+    headers
+        .iter()
+        .filter_map(|h| h.split_once(':'))
+        .map(|(key, value)| FetchHeader {
+            key: key.trim().to_owned(),
+            value: value.trim().to_owned(),
+        })
+        .collect()
+}
+
+pub(super) fn format_headers(headers: &[FetchHeader]) -> Vec<String> {
+    headers
+        .iter()
+        .map(|h| format!("{}: {}", h.key, h.value))
+        .collect()
+}
+
 #[async_trait::async_trait]
 impl DebotInterface for NetworkInterface {
     fn get_id(&self) -> String {
@@ -0,0 +1,309 @@
+use super::dinterface::{
+    decode_answer_id, get_arg, get_array_strings, get_num_arg, DebotInterface, InterfaceResult,
+};
+use super::network_interface::{format_headers, parse_headers};
+use crate::browser::{BrowserRef, FetchRequest};
+use crate::network_policy::NetworkPolicy;
+use futures::future::{select, Either};
+use futures_timer::Delay;
+use serde_json::{json, Value};
+use std::sync::Arc;
+use std::time::Duration;
+use ton_client::abi::Abi;
+
+const ABI: &str = r#"
+{
+	"ABI version": 2,
+	"version": "2.2",
+	"header": ["time"],
+	"functions": [
+		{
+			"name": "fetch",
+			"inputs": [
+				{"name":"answerId","type":"uint32"},
+				{"name":"method","type":"string"},
+				{"name":"url","type":"string"},
+				{"name":"headers","type":"string[]"},
+				{"name":"body","type":"bytes"},
+				{"name":"timeoutMs","type":"uint32"},
+				{"name":"maxResponseSize","type":"uint32"}
+			],
+			"outputs": [
+				{"name":"statusCode","type":"int32"},
+				{"name":"retHeaders","type":"string[]"},
+				{"name":"content","type":"bytes"},
+				{"name":"error","type":"string"}
+			]
+		}
+	]
+}
+"#;
+
+const ID: &str = "c3ce37da396b1572b45ca3e68c444bbb01490441e1d99da638ca50408369789c";
+
+// Used if DeBot passes zero timeout.
+const DEFAULT_TIMEOUT_MS: u32 = 60_000;
+// Used if DeBot passes zero response size limit.
+const DEFAULT_MAX_RESPONSE_SIZE: u32 = 1024 * 1024;
+
+/// Status codes returned instead of http status if request fails on engine side.
+pub(super) const STATUS_FETCH_FAILED: i32 = -1;
+pub(super) const STATUS_TIMEOUT: i32 = -2;
+pub(super) const STATUS_RESPONSE_TOO_LARGE: i32 = -3;
+pub(super) const STATUS_INVALID_REQUEST: i32 = -4;
+pub(super) const STATUS_ACCESS_DENIED: i32 = -5;
+
+const METHODS: [&str; 7] = ["GET", "HEAD", "POST", "PUT", "PATCH", "DELETE", "OPTIONS"];
+
+pub struct NetworkV2Interface {
+    browser: BrowserRef,
+    policy: Arc<NetworkPolicy>,
+}
+
+impl NetworkV2Interface {
+    pub fn new(browser: BrowserRef, policy: Arc<NetworkPolicy>) -> Self {
+        Self { browser, policy }
+    }
+
+    async fn fetch(&self, args: &Value) -> InterfaceResult {
+        let answer_id = decode_answer_id(args)?;
+        let method = get_arg(args, "method")?.to_uppercase();
+        let url = get_arg(args, "url")?;
+        let headers = get_array_strings(args, "headers")?;
+        let body = get_arg(args, "body")?;
+        let timeout_ms = match get_num_arg::<u32>(args, "timeoutMs")? {
+            0 => DEFAULT_TIMEOUT_MS,
+            timeout => timeout,
+        };
+        let max_size = match get_num_arg::<u32>(args, "maxResponseSize")? {
+            0 => DEFAULT_MAX_RESPONSE_SIZE,
+            size => size,
+        };
+        let max_size = self
+            .policy
+            .max_response_size
+            .map_or(max_size, |limit| max_size.min(limit));
+        if !METHODS.contains(&method.as_str()) {
+            return Ok(failure(
+                answer_id,
+                STATUS_INVALID_REQUEST,
+                format!("unsupported http method \"{method}\""),
+            ));
+        }
+        let body = match hex::decode(body) {
+            Ok(body) => body,
+            Err(e) => {
+                return Ok(failure(
+                    answer_id,
+                    STATUS_INVALID_REQUEST,
+                    format!("invalid request body: {e}"),
+                ))
+            }
+        };
+        if let Err(e) = self.policy.check_url(&url) {
+            return Ok(failure(answer_id, STATUS_ACCESS_DENIED, e));
+        }
+        let request = FetchRequest {
+            url,
+            method,
+            headers: parse_headers(headers),
+            body: if body.is_empty() {
+                None
+            } else {
+                Some(base64::encode(&body))
+            },
+            timeout_ms: Some(timeout_ms),
+            max_response_size: Some(max_size),
+            policy: Some((*self.policy).clone()),
+        };
+        let fetch = self.browser.fetch_binary(request);
+        let timeout = Delay::new(Duration::from_millis(timeout_ms as u64));
+        let response = match select(fetch, timeout).await {
+            Either::Left((Ok(response), _)) => response,
+            Either::Left((Err(e), _)) => {
+                return Ok(failure(answer_id, STATUS_FETCH_FAILED, format!("{e}")))
+            }
+            Either::Right(_) => {
+                return Ok(failure(
+                    answer_id,
+                    STATUS_TIMEOUT,
+                    format!("request timed out after {timeout_ms} ms"),
+                ))
+            }
+        };
+        let content = match base64::decode(&response.content) {
+            Ok(content) => content,
+            Err(e) => {
+                return Ok(failure(
+                    answer_id,
+                    STATUS_FETCH_FAILED,
+                    format!("invalid response content: {e}"),
+                ))
+            }
+        };
+        if content.len() > max_size as usize {
+            return Ok(failure(
+                answer_id,
+                STATUS_RESPONSE_TOO_LARGE,
+                format!("response size exceeds {max_size} bytes"),
+            ));
+        }
+        Ok((
+            answer_id,
+            json!({
+                "statusCode": response.status,
+                "retHeaders": format_headers(&response.headers),
+                "content": hex::encode(content),
+                "error": "",
+            }),
+        ))
+    }
+}
+
+/// Answer for request which failed on engine side.
+pub(super) fn failure(answer_id: u32, status: i32, error: String) -> (u32, Value) {
+    (
+        answer_id,
+        json!({
+            "statusCode": status,
+            "retHeaders": [],
+            "content": "",
+            "error": error,
+        }),
+    )
+}
+
+#[async_trait::async_trait]
+impl DebotInterface for NetworkV2Interface {
+    fn get_id(&self) -> String {
+        ID.to_string()
+    }
+
+    fn get_abi(&self) -> Abi {
+        Abi::Json(ABI.to_owned())
+    }
+
+    async fn call(&self, func: &str, args: &Value) -> InterfaceResult {
+        match func {
+            "fetch" => self.fetch(args).await,
+            _ => Err(format!("function \"{func}\" is not implemented")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::browser::{BrowserCallbacks, FetchResponse, LogLevel};
+    use crate::sdk_prelude::ClientResult;
+    use futures::executor::block_on;
+    use std::sync::atomic::{AtomicU32, Ordering};
+
+    /// Browser returning `content` or never answering if `content` is `None`.
+    struct MockBrowser {
+        content: Option<Vec<u8>>,
+        requests: AtomicU32,
+    }
+
+    #[async_trait::async_trait]
+    impl BrowserCallbacks for MockBrowser {
+        fn log(&self, _level: LogLevel, _msg: String) {}
+        async fn send(&self, _message: String) {}
+        async fn fetch_binary(&self, _request: FetchRequest) -> ClientResult<FetchResponse> {
+            self.requests.fetch_add(1, Ordering::Relaxed);
+            match &self.content {
+                Some(content) => Ok(FetchResponse {
+                    status: 200,
+                    headers: vec![],
+                    content: base64::encode(content),
+                }),
+                None => futures::future::pending().await,
+            }
+        }
+    }
+
+    fn fetch(content: Option<Vec<u8>>, policy: NetworkPolicy, args: Value) -> (Value, u32) {
+        let browser = Arc::new(MockBrowser {
+            content,
+            requests: AtomicU32::new(0),
+        });
+        let iface = NetworkV2Interface::new(browser.clone(), Arc::new(policy));
+        let (answer_id, result) = block_on(iface.call("fetch", &args)).unwrap();
+        assert_eq!(answer_id, 1);
+        (result, browser.requests.load(Ordering::Relaxed))
+    }
+
+    fn request(method: &str, url: &str, body: &str, timeout_ms: u32, max_size: u32) -> Value {
+        json!({
+            "answerId": "1",
+            "method": method,
+            "url": url,
+            "headers": ["Content-Type: text/plain"],
+            "body": body,
+            "timeoutMs": timeout_ms.to_string(),
+            "maxResponseSize": max_size.to_string(),
+        })
+    }
+
+    #[test]
+    fn test_success() {
+        let args = request("get", "https://example.com/data", "", 0, 0);
+        let (result, requests) = fetch(Some(b"data".to_vec()), NetworkPolicy::default(), args);
+        assert_eq!(requests, 1);
+        assert_eq!(result["statusCode"], json!(200));
+        assert_eq!(result["content"], json!(hex::encode("data")));
+        assert_eq!(result["error"], json!(""));
+    }
+
+    #[test]
+    fn test_timeout() {
+        let args = request("GET", "https://example.com", "", 10, 0);
+        let (result, _) = fetch(None, NetworkPolicy::default(), args);
+        assert_eq!(result["statusCode"], json!(STATUS_TIMEOUT));
+        assert_ne!(result["error"], json!(""));
+    }
+
+    #[test]
+    fn test_response_too_large() {
+        let args = request("GET", "https://example.com", "", 0, 3);
+        let (result, _) = fetch(Some(b"data".to_vec()), NetworkPolicy::default(), args);
+        assert_eq!(result["statusCode"], json!(STATUS_RESPONSE_TOO_LARGE));
+        assert_eq!(result["content"], json!(""));
+
+        // policy limit has priority over larger DeBot limit
+        let policy = NetworkPolicy {
+            max_response_size: Some(3),
+            ..Default::default()
+        };
+        let args = request("GET", "https://example.com", "", 0, 100);
+        let (result, _) = fetch(Some(b"data".to_vec()), policy, args);
+        assert_eq!(result["statusCode"], json!(STATUS_RESPONSE_TOO_LARGE));
+    }
+
+    #[test]
+    fn test_invalid_request() {
+        for args in [
+            request("CONNECT", "https://example.com", "", 0, 0),
+            request("POST", "https://example.com", "xyz", 0, 0),
+        ] {
+            let (result, requests) = fetch(Some(vec![]), NetworkPolicy::default(), args);
+            assert_eq!(result["statusCode"], json!(STATUS_INVALID_REQUEST));
+            assert_eq!(requests, 0);
+        }
+    }
+
+    #[test]
+    fn test_access_denied() {
+        let policy = NetworkPolicy {
+            https_only: true,
+            denied_hosts: vec!["*.evil.com".to_owned()],
+            ..Default::default()
+        };
+        for url in ["http://example.com", "https://api.evil.com", "https://user@example.com"] {
+            let args = request("GET", url, "", 0, 0);
+            let (result, requests) = fetch(Some(vec![]), policy.clone(), args);
+            assert_eq!(result["statusCode"], json!(STATUS_ACCESS_DENIED), "{url}");
+            assert_eq!(requests, 0);
+        }
+    }
+}
@@ -0,0 +1,141 @@
+use super::dinterface::{
+    decode_answer_id, get_arg, DebotInterface, InterfaceResult, MAX_INPUT_ATTEMPTS,
+};
+use crate::browser::{BrowserRef, LogLevel};
+use crate::sdk_prelude::*;
+use serde_json::{json, Value};
+
+const ABI: &str = r#"
+{
+	"ABI version": 2,
+	"version": "2.2",
+	"header": ["time"],
+	"functions": [
+		{
+			"name": "get",
+			"inputs": [
+				{"name":"answerId","type":"uint32"},
+				{"name":"prompt","type":"string"},
+				{"name":"min","type":"int256"},
+				{"name":"max","type":"int256"}
+			],
+			"outputs": [
+				{"name":"value","type":"int256"}
+			]
+		},
+		{
+			"name": "constructor",
+			"inputs": [
+			],
+			"outputs": [
+			]
+		}
+	],
+	"data": [
+	],
+	"events": [
+	],
+	"fields": [
+		{"name":"_pubkey","type":"uint256"},
+		{"name":"_timestamp","type":"uint64"},
+		{"name":"_constructorFlag","type":"bool"}
+	]
+}
+"#;
+
+const NUMBER_INPUT_ID: &str = "c5a9558b2664aed7dc3e6123436d544f13ffe69ab0e259412f48c6d1c8588401";
+
+pub struct NumberInputInterface {
+    browser: BrowserRef,
+}
+
+impl NumberInputInterface {
+    pub fn new(browser: BrowserRef) -> Self {
+        Self { browser }
+    }
+
+    async fn get(&self, args: &Value) -> InterfaceResult {
+        let answer_id = decode_answer_id(args)?;
+        let prompt = get_arg(args, "prompt")?;
+        let min = decode_abi_bigint(&get_arg(args, "min")?).map_err(|e| format!("{e}"))?;
+        let max = decode_abi_bigint(&get_arg(args, "max")?).map_err(|e| format!("{e}"))?;
+        if min > max {
+            return Err(format!("invalid range: min {min} is greater than max {max}"));
+        }
+        let (min_str, max_str) = (min.to_string(), max.to_string());
+        let mut attempt = 1;
+        loop {
+            let value = self
+                .browser
+                .input_number(prompt.clone(), min_str.clone(), max_str.clone())
+                .await
+                .map_err(|e| format!("{e}"))?;
+            match check_number(value.trim(), &min_str, &max_str) {
+                Ok(number) => return Ok((answer_id, json!({ "value": number }))),
+                Err(e) if attempt < MAX_INPUT_ATTEMPTS => {
+                    self.browser.log(LogLevel::User, format!("{e}. Try again."));
+                    attempt += 1;
+                }
+                Err(e) => return Err(e),
+            }
+        }
+    }
+}
+
+/// Checks that `value` is an integer in range [`min`, `max`] and returns it in decimal form.
+fn check_number(value: &str, min: &str, max: &str) -> Result<String, String> {
+    let number = decode_abi_bigint(value).map_err(|_| format!("invalid number \"{value}\""))?;
+    let min = decode_abi_bigint(min).map_err(|e| format!("{e}"))?;
+    let max = decode_abi_bigint(max).map_err(|e| format!("{e}"))?;
+    if number < min || number > max {
+        return Err(format!("number {number} is out of range [{min}, {max}]"));
+    }
+    Ok(number.to_string())
+}
+
+#[async_trait::async_trait]
+impl DebotInterface for NumberInputInterface {
+    fn get_id(&self) -> String {
+        NUMBER_INPUT_ID.to_string()
+    }
+
+    fn get_abi(&self) -> Abi {
+        Abi::Json(ABI.to_owned())
+    }
+
+    async fn call(&self, func: &str, args: &Value) -> InterfaceResult {
+        match func {
+            "get" => self.get(args).await,
+            _ => Err(format!("function \"{func}\" is not implemented")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::check_number;
+
+    #[test]
+    fn test_number_bounds() {
+        assert_eq!(check_number("-10", "-10", "10"), Ok("-10".to_owned()));
+        assert_eq!(check_number("10", "-10", "10"), Ok("10".to_owned()));
+        assert_eq!(check_number("0x0a", "-10", "10"), Ok("10".to_owned()));
+        assert_eq!(
+            check_number("11", "-10", "10"),
+            Err("number 11 is out of range [-10, 10]".to_owned())
+        );
+        assert!(check_number("-11", "-10", "10").is_err());
+        assert!(check_number("1.5", "-10", "10").is_err());
+        assert!(check_number("", "-10", "10").is_err());
+    }
+
+    #[test]
+    fn test_big_number_bounds() {
+        let max = u128::MAX.to_string();
+        let min = format!("-{max}");
+        assert_eq!(check_number(&min, &min, &max), Ok(min.clone()));
+        assert_eq!(check_number(&max, &min, &max), Ok(max.clone()));
+        assert!(check_number(&format!("{max}0"), &min, &max).is_err());
+        assert!(check_number(&format!("{min}0"), &min, &max).is_err());
+    }
+}
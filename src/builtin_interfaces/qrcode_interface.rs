@@ -0,0 +1,291 @@
+use super::dinterface::{decode_answer_id, get_arg, DebotInterface, InterfaceResult, ZERO_ADDRESS};
+use crate::browser::{debug, log, BrowserRef, LogLevel};
+use crate::dengine::load_ton_address;
+use crate::sdk_prelude::*;
+use serde_json::{json, Value};
+
+const ABI: &str = r#"
+{
+	"ABI version": 2,
+	"version": "2.2",
+	"header": ["time"],
+	"functions": [
+		{
+			"name": "scan",
+			"inputs": [
+				{"name":"answerId","type":"uint32"}
+			],
+			"outputs": [
+				{"name":"value","type":"string"}
+			]
+		},
+		{
+			"name": "read",
+			"inputs": [
+				{"name":"answerId","type":"uint32"},
+				{"name":"prompt","type":"string"}
+			],
+			"outputs": [
+				{"name":"value","type":"string"},
+				{"name":"isTransfer","type":"bool"},
+				{"name":"recipient","type":"address"},
+				{"name":"amount","type":"uint128"},
+				{"name":"comment","type":"string"}
+			]
+		},
+		{
+			"name": "constructor",
+			"inputs": [
+			],
+			"outputs": [
+			]
+		}
+	],
+	"data": [
+	],
+	"events": [
+	],
+	"fields": [
+		{"name":"_pubkey","type":"uint256"},
+		{"name":"_timestamp","type":"uint64"},
+		{"name":"_constructorFlag","type":"bool"}
+	]
+}
+"#;
+
+const QRCODE_ID: &str = "3e6029dd5452523218794f5459b8dac1dd5dee008600bb2a02868ebbbd4dab5d";
+
+const TRANSFER_LINK_PREFIX: &str = "ton://transfer/";
+
+/// Transfer parameters encoded in `ton://transfer/<address>?amount=<nanotokens>&text=<comment>` link.
+#[derive(Debug, PartialEq)]
+struct TransferLink {
+    recipient: String,
+    amount: u128,
+    comment: String,
+}
+
+pub struct QRCodeInterface {
+    browser: BrowserRef,
+}
+
+impl QRCodeInterface {
+    pub fn new(browser: BrowserRef) -> Self {
+        Self { browser }
+    }
+
+    async fn scan(&self, args: &Value) -> InterfaceResult {
+        let answer_id = decode_answer_id(args)?;
+        let value = self.scan_qr_code(String::new()).await?;
+        Ok((answer_id, json!({ "value": value })))
+    }
+
+    /// Scans QR code. If it contains valid transfer link, link is parsed
+    /// and transfer parameters are returned along with raw value.
+    /// Malformed transfer link is returned as raw value only.
+    async fn read(&self, args: &Value) -> InterfaceResult {
+        let answer_id = decode_answer_id(args)?;
+        let prompt = get_arg(args, "prompt")?;
+        let value = self.scan_qr_code(prompt).await?;
+        let transfer = match parse_transfer_link(&value) {
+            Ok(transfer) => Some(transfer),
+            Err(e) => {
+                if value.starts_with(TRANSFER_LINK_PREFIX) {
+                    debug!(self.browser, "malformed transfer link: {}", e);
+                }
+                None
+            }
+        };
+        Ok((
+            answer_id,
+            json!({
+                "value": value,
+                "isTransfer": transfer.is_some(),
+                "recipient": transfer.as_ref().map_or(ZERO_ADDRESS, |t| t.recipient.as_str()),
+                "amount": transfer.as_ref().map_or(0, |t| t.amount).to_string(),
+                "comment": transfer.as_ref().map_or("", |t| t.comment.as_str()),
+            }),
+        ))
+    }
+
+    async fn scan_qr_code(&self, prompt: String) -> Result<String, String> {
+        self.browser
+            .scan_qr_code(prompt)
+            .await
+            .map_err(|e| format!("{e}"))
+    }
+}
+
+fn parse_transfer_link(link: &str) -> Result<TransferLink, String> {
+    let rest = link
+        .strip_prefix(TRANSFER_LINK_PREFIX)
+        .ok_or_else(|| format!("invalid transfer link \"{link}\""))?;
+    let (address, query) = rest.split_once('?').unwrap_or((rest, ""));
+    let recipient = load_ton_address(&percent_decode(address)?)?;
+    let mut amount = 0;
+    let mut comment = String::new();
+    for pair in query.split('&').filter(|p| !p.is_empty()) {
+        let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+        match key {
+            "amount" => {
+                amount = value
+                    .parse::<u128>()
+                    .map_err(|e| format!("invalid amount in transfer link: {e}"))?
+            }
+            "text" => comment = percent_decode(&value.replace('+', " "))?,
+            _ => {}
+        }
+    }
+    Ok(TransferLink {
+        recipient,
+        amount,
+        comment,
+    })
+}
+
+fn percent_decode(value: &str) -> Result<String, String> {
+    let bytes = value.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'%' {
+            let hex = value
+                .get(i + 1..i + 3)
+                .ok_or_else(|| format!("invalid percent encoding in \"{value}\""))?;
+            let byte = u8::from_str_radix(hex, 16)
+                .map_err(|_| format!("invalid percent encoding in \"{value}\""))?;
+            decoded.push(byte);
+            i += 3;
+        } else {
+            decoded.push(bytes[i]);
+            i += 1;
+        }
+    }
+    String::from_utf8(decoded).map_err(|e| format!("invalid utf8 in \"{value}\": {e}"))
+}
+
+#[async_trait::async_trait]
+impl DebotInterface for QRCodeInterface {
+    fn get_id(&self) -> String {
+        QRCODE_ID.to_string()
+    }
+
+    fn get_abi(&self) -> Abi {
+        Abi::Json(ABI.to_owned())
+    }
+
+    async fn call(&self, func: &str, args: &Value) -> InterfaceResult {
+        match func {
+            "scan" => self.scan(args).await,
+            "read" => self.read(args).await,
+            _ => Err(format!("function \"{func}\" is not implemented")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::browser::BrowserCallbacks;
+    use futures::executor::block_on;
+
+    const ADDRESS: &str = "0:1111111111111111111111111111111111111111111111111111111111111111";
+
+    #[test]
+    fn test_percent_decode() {
+        assert_eq!(percent_decode("plain").unwrap(), "plain");
+        assert_eq!(percent_decode("a%20b%2Bc").unwrap(), "a b+c");
+        assert_eq!(
+            percent_decode("%D0%BF%D1%80%D0%B8%D0%B2%D0%B5%D1%82").unwrap(),
+            "привет"
+        );
+        assert!(percent_decode("100%").is_err());
+        assert!(percent_decode("%2").is_err());
+        assert!(percent_decode("%zz").is_err());
+        assert!(percent_decode("%FF").is_err());
+    }
+
+    #[test]
+    fn test_parse_transfer_link() {
+        let link = format!(
+            "ton://transfer/{}?amount=1500000000&text=for+coffee%21",
+            ADDRESS.replace(':', "%3A")
+        );
+        assert_eq!(
+            parse_transfer_link(&link).unwrap(),
+            TransferLink {
+                recipient: ADDRESS.to_owned(),
+                amount: 1_500_000_000,
+                comment: "for coffee!".to_owned(),
+            }
+        );
+        let link = format!("ton://transfer/{ADDRESS}?foo=bar&&");
+        assert_eq!(
+            parse_transfer_link(&link).unwrap(),
+            TransferLink {
+                recipient: ADDRESS.to_owned(),
+                amount: 0,
+                comment: String::new(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_transfer_link_errors() {
+        let links = [
+            format!("ton://pay/{ADDRESS}"),
+            "ton://transfer/0:123?amount=1".to_owned(),
+            format!("ton://transfer/{ADDRESS}?amount=-1"),
+            format!("ton://transfer/{ADDRESS}?amount=1.5"),
+            format!("ton://transfer/{ADDRESS}?text=%E0%A4"),
+        ];
+        for link in links {
+            assert!(parse_transfer_link(&link).is_err(), "{link}");
+        }
+    }
+
+    /// Browser which scans QR code with the given value.
+    struct Scanner(String);
+
+    #[async_trait::async_trait]
+    impl BrowserCallbacks for Scanner {
+        fn log(&self, _level: LogLevel, _msg: String) {}
+        async fn send(&self, _message: String) {}
+        async fn scan_qr_code(&self, _prompt: String) -> ClientResult<String> {
+            Ok(self.0.clone())
+        }
+    }
+
+    fn read(value: &str) -> Value {
+        let iface = QRCodeInterface::new(Arc::new(Scanner(value.to_owned())));
+        let args = json!({ "answerId": "1", "prompt": "" });
+        block_on(iface.call("read", &args)).unwrap().1
+    }
+
+    #[test]
+    fn test_read() {
+        let link = format!("ton://transfer/{ADDRESS}?amount=5&text=hi");
+        assert_eq!(
+            read(&link),
+            json!({
+                "value": link,
+                "isTransfer": true,
+                "recipient": ADDRESS,
+                "amount": "5",
+                "comment": "hi",
+            })
+        );
+        for value in ["ton://transfer/0:123?amount=1", "ton://transfer/", "hello"] {
+            assert_eq!(
+                read(value),
+                json!({
+                    "value": value,
+                    "isTransfer": false,
+                    "recipient": ZERO_ADDRESS,
+                    "amount": "0",
+                    "comment": "",
+                })
+            );
+        }
+    }
+}
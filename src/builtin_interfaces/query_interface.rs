@@ -63,12 +63,15 @@ const ID: &str = "5c6fd81616cdfb963632109c42144a3a885c8d0f2e8deb5d8e15872fb92f28
 
 #[derive(Clone)]
 #[repr(u8)]
-enum QueryStatus {
+pub(super) enum QueryStatus {
     Success = 0,
     FilterError = 1,
     NetworkError = 2,
     PackingError = 3,
     VariablesError = 4,
+    InvalidArgument = 5,
+    DecodingError = 6,
+    NotFound = 7,
 }
 
 pub struct QueryInterface {
@@ -152,7 +155,7 @@ impl QueryInterface {
         Ok(result.result)
     }
 
-    fn pack_objects(json_objects: Vec<JsonValue>) -> Option<Vec<Value>> {
+    pub(super) fn pack_objects(json_objects: Vec<JsonValue>) -> Option<Vec<Value>> {
         let mut objects = vec![];
         for obj in json_objects {
             objects.push(pack(obj)?);
@@ -160,7 +163,7 @@ impl QueryInterface {
         Some(objects)
     }
 
-    async fn run_wait_for_collection(
+    pub(super) async fn run_wait_for_collection(
         &self,
         collection: String,
         filter: String,
@@ -247,7 +250,7 @@ impl QueryInterface {
         Ok(result.result)
     }
 
-    async fn query(&self, args: &JsonValue) -> InterfaceResult {
+    pub(super) async fn query(&self, args: &JsonValue) -> InterfaceResult {
         let answer_id = decode_answer_id(args)?;
         let query_str = get_arg(args, "query")?;
         let variables_str = get_arg(args, "variables")?;
@@ -0,0 +1,776 @@
+use super::dinterface::{decode_answer_id, get_arg, get_num_arg, DebotInterface, InterfaceResult};
+use super::json_lib_utils::pack;
+use super::query_interface::{QueryInterface, QueryStatus};
+use crate::browser::BrowserRef;
+use crate::sdk_prelude::*;
+use crate::subscriptions::{CollectionEvent, Subscriptions};
+use crate::dengine::EMPTY_CELL;
+use crate::JsonValue;
+use serde_json::json;
+use ton_client::abi::{
+    decode_account_data, encode_boc, AbiParam, MessageBodyType, ParamsOfAbiEncodeBoc,
+    ParamsOfDecodeAccountData,
+};
+
+const ABI: &str = r#"
+{
+	"ABI version": 2,
+	"version": "2.2",
+	"header": ["time"],
+	"functions": [
+		{
+			"name": "collection",
+			"inputs": [
+				{"name":"answerId","type":"uint32"},
+				{"name":"collectionType","type":"uint8"},
+				{"name":"queryFilter","type":"string"},
+				{"name":"returnFilter","type":"string"},
+				{"name":"limit","type":"uint32"},
+				{"components":[{"name":"path","type":"string"},{"name":"direction","type":"uint8"}],"name":"orderBy","type":"tuple"},
+				{"name":"cursor","type":"string"}
+			],
+			"outputs": [
+				{"name":"status","type":"uint8"},
+				{"components":[{"name":"kind","type":"uint8"},{"name":"value","type":"cell"},{"name":"object","type":"map(uint256,cell)"},{"components":[{"name":"cell","type":"cell"}],"name":"array","type":"tuple[]"}],"name":"objects","type":"tuple[]"},
+				{"name":"hasMore","type":"bool"},
+				{"name":"nextCursor","type":"string"}
+			]
+		},
+		{
+			"name": "aggregateCollection",
+			"inputs": [
+				{"name":"answerId","type":"uint32"},
+				{"name":"collectionType","type":"uint8"},
+				{"name":"queryFilter","type":"string"},
+				{"components":[{"name":"field","type":"string"},{"name":"fn","type":"uint8"}],"name":"fields","type":"tuple[]"}
+			],
+			"outputs": [
+				{"name":"status","type":"uint8"},
+				{"name":"values","type":"string[]"}
+			]
+		},
+		{
+			"name": "queryMessages",
+			"inputs": [
+				{"name":"answerId","type":"uint32"},
+				{"name":"queryFilter","type":"string"},
+				{"name":"limit","type":"uint32"},
+				{"name":"abi","type":"string"}
+			],
+			"outputs": [
+				{"name":"status","type":"uint8"},
+				{"components":[{"name":"id","type":"string"},{"name":"name","type":"string"},{"name":"bodyType","type":"uint8"},{"name":"params","type":"cell"}],"name":"messages","type":"tuple[]"}
+			]
+		},
+		{
+			"name": "queryEvents",
+			"inputs": [
+				{"name":"answerId","type":"uint32"},
+				{"name":"addr","type":"address"},
+				{"name":"limit","type":"uint32"},
+				{"name":"abi","type":"string"}
+			],
+			"outputs": [
+				{"name":"status","type":"uint8"},
+				{"components":[{"name":"id","type":"string"},{"name":"name","type":"string"},{"name":"bodyType","type":"uint8"},{"name":"params","type":"cell"}],"name":"events","type":"tuple[]"}
+			]
+		},
+		{
+			"name": "queryAccountData",
+			"inputs": [
+				{"name":"answerId","type":"uint32"},
+				{"name":"addr","type":"address"},
+				{"name":"abi","type":"string"}
+			],
+			"outputs": [
+				{"name":"status","type":"uint8"},
+				{"name":"data","type":"cell"}
+			]
+		},
+		{
+			"name": "subscribe",
+			"inputs": [
+				{"name":"answerId","type":"uint32"},
+				{"name":"collectionType","type":"uint8"},
+				{"name":"queryFilter","type":"string"},
+				{"name":"returnFilter","type":"string"},
+				{"name":"callbackId","type":"uint32"}
+			],
+			"outputs": [
+				{"name":"status","type":"uint8"},
+				{"name":"subscriptionId","type":"uint32"}
+			]
+		},
+		{
+			"name": "unsubscribe",
+			"inputs": [
+				{"name":"answerId","type":"uint32"},
+				{"name":"subscriptionId","type":"uint32"}
+			],
+			"outputs": [
+				{"name":"status","type":"uint8"}
+			]
+		},
+		{
+			"name": "waitForCollection",
+			"inputs": [
+				{"name":"answerId","type":"uint32"},
+				{"name":"collectionType","type":"uint8"},
+				{"name":"queryFilter","type":"string"},
+				{"name":"returnFilter","type":"string"},
+				{"name":"timeout","type":"uint32"}
+			],
+			"outputs": [
+				{"name":"status","type":"uint8"},
+				{"components":[{"name":"kind","type":"uint8"},{"name":"value","type":"cell"},{"name":"object","type":"map(uint256,cell)"},{"components":[{"name":"cell","type":"cell"}],"name":"array","type":"tuple[]"}],"name":"object","type":"tuple"}
+			]
+		},
+		{
+			"name": "query",
+			"inputs": [
+				{"name":"answerId","type":"uint32"},
+				{"name":"query","type":"string"},
+				{"name":"variables","type":"string"}
+			],
+			"outputs": [
+				{"name":"status","type":"uint8"},
+				{"components":[{"name":"kind","type":"uint8"},{"name":"value","type":"cell"},{"name":"object","type":"map(uint256,cell)"},{"components":[{"name":"cell","type":"cell"}],"name":"array","type":"tuple[]"}],"name":"object","type":"tuple"}
+			]
+		}
+	]
+}
+"#;
+
+const ID: &str = "daafc25b626abe6afaddc3aa7d34ab94a6f95ab967a057a6ee8bcca49c3cb568";
+
+// Used if DeBot passes zero limit.
+const DEFAULT_LIMIT: u32 = 50;
+// Used if DeBot passes empty orderBy path.
+const DEFAULT_ORDER_PATH: &str = "id";
+
+/// Query interface v2.
+///
+/// Pagination is cursor-based: `nextCursor` contains JSON value of `orderBy.path`
+/// field of the last returned object. The next page is requested with the same
+/// arguments and this cursor. Path should point to a unique field (e.g. `id`),
+/// otherwise objects with equal values on page boundary can be skipped.
+///
+/// `queryMessages`, `queryEvents` and `queryAccountData` decode message bodies and
+/// account data with the given ABI. Decoded values are returned as a cell which
+/// DeBot decodes with `abi.decode` using parameters of the function, event or
+/// contract fields. Messages which can't be decoded with the ABI are skipped.
+/// If account doesn't exist or has no data, `queryAccountData` returns `NotFound` status.
+///
+/// Updates of subscriptions are delivered asynchronously by calling DeBot function
+/// `callbackId` with arguments `(uint32 subscriptionId, <object tuple> object)`.
+pub struct QueryV2Interface {
+    ton: TonClient,
+    browser: BrowserRef,
+    v1: QueryInterface,
+    subscriptions: Arc<Subscriptions>,
+}
+
+impl QueryV2Interface {
+    pub fn new(ton: TonClient, browser: BrowserRef, subscriptions: Arc<Subscriptions>) -> Self {
+        Self {
+            ton,
+            v1: QueryInterface::new(browser.clone()),
+            browser,
+            subscriptions,
+        }
+    }
+
+    async fn collection(&self, args: &JsonValue) -> InterfaceResult {
+        let answer_id = decode_answer_id(args)?;
+        let collection_type = get_num_arg::<u8>(args, "collectionType")?;
+        let query_filter = get_arg(args, "queryFilter")?;
+        let return_filter = get_arg(args, "returnFilter")?;
+        let limit = match get_num_arg::<u32>(args, "limit")? {
+            0 => DEFAULT_LIMIT,
+            limit => limit,
+        };
+        let path = match get_arg(&args["orderBy"], "path")? {
+            path if path.is_empty() => DEFAULT_ORDER_PATH.to_owned(),
+            path => path,
+        };
+        let direction = match get_num_arg::<u8>(&args["orderBy"], "direction")? {
+            0 => SortDirection::ASC,
+            _ => SortDirection::DESC,
+        };
+        let cursor = get_arg(args, "cursor")?;
+
+        let result = self
+            .collection_page(
+                collection_type,
+                query_filter,
+                return_filter,
+                limit,
+                OrderBy { path, direction },
+                cursor,
+            )
+            .await;
+        let (status, objects, has_more, next_cursor) = match result {
+            Ok((json_objects, next_cursor)) => match QueryInterface::pack_objects(json_objects) {
+                Some(objects) => (
+                    QueryStatus::Success,
+                    objects,
+                    next_cursor.is_some(),
+                    next_cursor.unwrap_or_default(),
+                ),
+                None => (QueryStatus::PackingError, vec![], false, String::new()),
+            },
+            Err(status) => (status, vec![], false, String::new()),
+        };
+
+        Ok((
+            answer_id,
+            json!({
+                "status": status as u8,
+                "objects": objects,
+                "hasMore": has_more,
+                "nextCursor": next_cursor,
+            }),
+        ))
+    }
+
+    /// Returns objects of one page and cursor of the next page if it exists.
+    async fn collection_page(
+        &self,
+        collection_type: u8,
+        filter: String,
+        result: String,
+        limit: u32,
+        order_by: OrderBy,
+        cursor: String,
+    ) -> Result<(Vec<JsonValue>, Option<String>), QueryStatus> {
+        let collection = collection_name(collection_type)?;
+        let mut filter = parse_filter(&filter)?;
+        if !cursor.is_empty() {
+            let cursor: JsonValue =
+                serde_json::from_str(&cursor).map_err(|_| QueryStatus::InvalidArgument)?;
+            filter = cursor_filter(filter, &order_by, cursor)?;
+        }
+        // Field used as cursor must be present in results.
+        let result = format!("{} {}", result, selection(&order_by.path));
+        let path = order_by.path.clone();
+        let mut objects = self
+            .browser
+            .query_collection(ParamsOfQueryCollection {
+                collection,
+                filter: Some(filter),
+                result,
+                order: Some(vec![order_by]),
+                limit: Some(limit.saturating_add(1)),
+            })
+            .await
+            .map_err(|_| QueryStatus::NetworkError)?
+            .result;
+        if objects.len() <= limit as usize {
+            return Ok((objects, None));
+        }
+        objects.truncate(limit as usize);
+        let next_cursor = objects
+            .last()
+            .and_then(|obj| obj.pointer(&format!("/{}", path.replace('.', "/"))))
+            .map(|value| value.to_string())
+            .ok_or(QueryStatus::PackingError)?;
+        Ok((objects, Some(next_cursor)))
+    }
+
+    async fn aggregate_collection(&self, args: &JsonValue) -> InterfaceResult {
+        let answer_id = decode_answer_id(args)?;
+        let collection_type = get_num_arg::<u8>(args, "collectionType")?;
+        let query_filter = get_arg(args, "queryFilter")?;
+        let mut fields = vec![];
+        for field in args["fields"].as_array().ok_or("\"fields\" not found")? {
+            fields.push((get_arg(field, "field")?, get_num_arg::<u8>(field, "fn")?));
+        }
+
+        let result = self
+            .run_aggregate(collection_type, query_filter, fields)
+            .await;
+        let (status, values) = match result {
+            Ok(values) => (QueryStatus::Success, values),
+            Err(status) => (status, vec![]),
+        };
+
+        Ok((
+            answer_id,
+            json!({
+                "status": status as u8,
+                "values": values,
+            }),
+        ))
+    }
+
+    async fn run_aggregate(
+        &self,
+        collection_type: u8,
+        filter: String,
+        fields: Vec<(String, u8)>,
+    ) -> Result<Vec<String>, QueryStatus> {
+        let collection = collection_name(collection_type)?;
+        let filter = parse_filter(&filter)?;
+        let fields = fields
+            .into_iter()
+            .map(|(field, func)| {
+                let fn_ = match func {
+                    0 => AggregationFn::COUNT,
+                    1 => AggregationFn::SUM,
+                    2 => AggregationFn::MIN,
+                    3 => AggregationFn::MAX,
+                    _ => return Err(QueryStatus::InvalidArgument),
+                };
+                Ok(FieldAggregation { field, fn_ })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        let result = self
+            .browser
+            .aggregate_collection(ParamsOfAggregateCollection {
+                collection,
+                filter: Some(filter),
+                fields: Some(fields),
+            })
+            .await
+            .map_err(|_| QueryStatus::NetworkError)?;
+        let values = result
+            .values
+            .as_array()
+            .ok_or(QueryStatus::PackingError)?
+            .iter()
+            .map(|value| match value {
+                JsonValue::String(s) => s.clone(),
+                other => other.to_string(),
+            })
+            .collect();
+        Ok(values)
+    }
+
+    async fn query_messages(&self, args: &JsonValue) -> InterfaceResult {
+        let answer_id = decode_answer_id(args)?;
+        let query_filter = get_arg(args, "queryFilter")?;
+        let limit = get_num_arg::<u32>(args, "limit")?;
+        let abi = get_arg(args, "abi")?;
+
+        let result = match parse_filter(&query_filter) {
+            Ok(filter) => self.decode_messages(filter, None, limit, abi).await,
+            Err(status) => Err(status),
+        };
+        let (status, messages) = match result {
+            Ok(messages) => (QueryStatus::Success, messages),
+            Err(status) => (status, vec![]),
+        };
+
+        Ok((
+            answer_id,
+            json!({
+                "status": status as u8,
+                "messages": messages,
+            }),
+        ))
+    }
+
+    async fn query_events(&self, args: &JsonValue) -> InterfaceResult {
+        let answer_id = decode_answer_id(args)?;
+        let addr = get_arg(args, "addr")?;
+        let limit = get_num_arg::<u32>(args, "limit")?;
+        let abi = get_arg(args, "abi")?;
+
+        // External outbound messages of the account, newest first.
+        let filter = json!({
+            "src": { "eq": addr },
+            "msg_type": { "eq": 2 },
+        });
+        let order_by = OrderBy {
+            path: "created_lt".to_owned(),
+            direction: SortDirection::DESC,
+        };
+        let result = self.decode_messages(filter, Some(order_by), limit, abi).await;
+        let (status, events) = match result {
+            Ok(events) => (QueryStatus::Success, events),
+            Err(status) => (status, vec![]),
+        };
+
+        Ok((
+            answer_id,
+            json!({
+                "status": status as u8,
+                "events": events,
+            }),
+        ))
+    }
+
+    /// Queries messages and decodes their bodies with `abi`.
+    async fn decode_messages(
+        &self,
+        filter: JsonValue,
+        order_by: Option<OrderBy>,
+        limit: u32,
+        abi: String,
+    ) -> Result<Vec<JsonValue>, QueryStatus> {
+        let abi_json: JsonValue =
+            serde_json::from_str(&abi).map_err(|_| QueryStatus::InvalidArgument)?;
+        let limit = match limit {
+            0 => DEFAULT_LIMIT,
+            limit => limit,
+        };
+        let messages = self
+            .browser
+            .query_collection(ParamsOfQueryCollection {
+                collection: "messages".to_owned(),
+                filter: Some(filter),
+                result: "id body msg_type".to_owned(),
+                order: order_by.map(|order_by| vec![order_by]),
+                limit: Some(limit),
+            })
+            .await
+            .map_err(|_| QueryStatus::NetworkError)?
+            .result;
+        let abi = Abi::Json(abi);
+        let mut decoded = vec![];
+        for message in messages {
+            // Messages which can't be decoded with the ABI are skipped.
+            if let Ok(message) = self.decode_message(&abi, &abi_json, &message).await {
+                decoded.push(message);
+            }
+        }
+        Ok(decoded)
+    }
+
+    /// Decodes body of message returned by `decode_messages` query.
+    async fn decode_message(
+        &self,
+        abi: &Abi,
+        abi_json: &JsonValue,
+        message: &JsonValue,
+    ) -> Result<JsonValue, QueryStatus> {
+        let body = message["body"]
+            .as_str()
+            .ok_or(QueryStatus::DecodingError)?
+            .to_owned();
+        let body = decode_message_body(
+            self.ton.clone(),
+            ParamsOfDecodeMessageBody {
+                abi: abi.clone(),
+                body,
+                // Internal messages have type 0, external inbound 1 and outbound 2.
+                is_internal: message["msg_type"].as_u64() == Some(0),
+                allow_partial: true,
+                ..Default::default()
+            },
+        )
+        .map_err(|_| QueryStatus::DecodingError)?;
+        let body_type = match body.body_type {
+            MessageBodyType::Input => 0,
+            MessageBodyType::Output => 1,
+            MessageBodyType::InternalOutput => 2,
+            MessageBodyType::Event => 3,
+        };
+        let section = match body.body_type {
+            MessageBodyType::Event => ("events", "inputs"),
+            MessageBodyType::Input => ("functions", "inputs"),
+            _ => ("functions", "outputs"),
+        };
+        let params = abi_json[section.0]
+            .as_array()
+            .and_then(|items| items.iter().find(|item| item["name"] == body.name.as_str()))
+            .map(|item| item[section.1].clone())
+            .ok_or(QueryStatus::DecodingError)?;
+        let cell = self
+            .encode_params(params, body.value.unwrap_or_else(|| json!({})))
+            .await?;
+        Ok(json!({
+            "id": message["id"].as_str().unwrap_or_default(),
+            "name": body.name,
+            "bodyType": body_type,
+            "params": cell,
+        }))
+    }
+
+    async fn query_account_data(&self, args: &JsonValue) -> InterfaceResult {
+        let answer_id = decode_answer_id(args)?;
+        let addr = get_arg(args, "addr")?;
+        let abi = get_arg(args, "abi")?;
+
+        let (status, data) = match self.decode_account_data(addr, abi).await {
+            Ok(data) => (QueryStatus::Success, data),
+            Err(status) => (status, EMPTY_CELL.to_owned()),
+        };
+
+        Ok((
+            answer_id,
+            json!({
+                "status": status as u8,
+                "data": data,
+            }),
+        ))
+    }
+
+    async fn decode_account_data(&self, addr: String, abi: String) -> Result<String, QueryStatus> {
+        let abi_json: JsonValue =
+            serde_json::from_str(&abi).map_err(|_| QueryStatus::InvalidArgument)?;
+        let accounts = self
+            .browser
+            .query_collection(ParamsOfQueryCollection {
+                collection: "accounts".to_owned(),
+                filter: Some(json!({ "id": { "eq": addr } })),
+                result: "data".to_owned(),
+                order: None,
+                limit: Some(1),
+            })
+            .await
+            .map_err(|_| QueryStatus::NetworkError)?
+            .result;
+        let data = accounts
+            .first()
+            .and_then(|account| account["data"].as_str())
+            .ok_or(QueryStatus::NotFound)?
+            .to_owned();
+        let decoded = decode_account_data(
+            self.ton.clone(),
+            ParamsOfDecodeAccountData {
+                abi: Abi::Json(abi),
+                data,
+                allow_partial: true,
+            },
+        )
+        .map_err(|_| QueryStatus::DecodingError)?
+        .data;
+        // ABI 2.1+ describes the whole storage in "fields", older versions only "data".
+        let params = match abi_json["fields"].as_array() {
+            Some(fields) if !fields.is_empty() => abi_json["fields"].clone(),
+            _ => abi_json["data"].clone(),
+        };
+        self.encode_params(params, decoded).await
+    }
+
+    /// Serializes decoded values into a cell according to ABI parameters.
+    async fn encode_params(&self, params: JsonValue, data: JsonValue) -> Result<String, QueryStatus> {
+        let params: Vec<AbiParam> =
+            serde_json::from_value(params).map_err(|_| QueryStatus::DecodingError)?;
+        let result = encode_boc(
+            self.ton.clone(),
+            ParamsOfAbiEncodeBoc {
+                params,
+                data,
+                boc_cache: None,
+            },
+        )
+        .await
+        .map_err(|_| QueryStatus::PackingError)?;
+        Ok(result.boc)
+    }
+
+    async fn subscribe(&self, args: &JsonValue) -> InterfaceResult {
+        let answer_id = decode_answer_id(args)?;
+        let collection_type = get_num_arg::<u8>(args, "collectionType")?;
+        let query_filter = get_arg(args, "queryFilter")?;
+        let return_filter = get_arg(args, "returnFilter")?;
+        let callback_id = get_num_arg::<u32>(args, "callbackId")?;
+
+        let result = self
+            .run_subscribe(collection_type, query_filter, return_filter, callback_id)
+            .await;
+        let (status, subscription_id) = match result {
+            Ok(subscription_id) => (QueryStatus::Success, subscription_id),
+            Err(status) => (status, 0),
+        };
+
+        Ok((
+            answer_id,
+            json!({
+                "status": status as u8,
+                "subscriptionId": subscription_id,
+            }),
+        ))
+    }
+
+    async fn run_subscribe(
+        &self,
+        collection_type: u8,
+        filter: String,
+        result: String,
+        callback_id: u32,
+    ) -> Result<u32, QueryStatus> {
+        let collection = collection_name(collection_type)?;
+        let filter = parse_filter(&filter)?;
+        let events = self.subscriptions.add(callback_id);
+        let subscription_id = events.subscription_id();
+        let params = ParamsOfSubscribeCollection {
+            collection,
+            filter: Some(filter),
+            result,
+        };
+        if self.browser.subscribe_collection(params, events).await.is_err() {
+            self.subscriptions.remove(subscription_id);
+            return Err(QueryStatus::NetworkError);
+        }
+        Ok(subscription_id)
+    }
+
+    async fn unsubscribe(&self, args: &JsonValue) -> InterfaceResult {
+        let answer_id = decode_answer_id(args)?;
+        let subscription_id = get_num_arg::<u32>(args, "subscriptionId")?;
+
+        let status = if !self.subscriptions.remove(subscription_id) {
+            QueryStatus::InvalidArgument
+        } else if self
+            .browser
+            .unsubscribe_collection(subscription_id)
+            .await
+            .is_err()
+        {
+            QueryStatus::NetworkError
+        } else {
+            QueryStatus::Success
+        };
+
+        Ok((answer_id, json!({ "status": status as u8 })))
+    }
+
+    async fn wait_for_collection(&self, args: &JsonValue) -> InterfaceResult {
+        let answer_id = decode_answer_id(args)?;
+        let collection_type = get_num_arg::<u8>(args, "collectionType")?;
+        let query_filter = get_arg(args, "queryFilter")?;
+        let return_filter = get_arg(args, "returnFilter")?;
+        let timeout = get_num_arg::<u32>(args, "timeout")?;
+
+        let result = match collection_name(collection_type) {
+            Ok(collection) => {
+                self.v1
+                    .run_wait_for_collection(collection, query_filter, return_filter, timeout)
+                    .await
+            }
+            Err(status) => Err(status),
+        };
+        let (status, object) = match result {
+            Ok(json_object) => match pack(json_object) {
+                Some(object) => (QueryStatus::Success, object),
+                None => (QueryStatus::PackingError, pack(json!({})).unwrap()),
+            },
+            Err(status) => (status, pack(json!({})).unwrap()),
+        };
+
+        Ok((
+            answer_id,
+            json!({
+                "status": status as u8,
+                "object": object,
+            }),
+        ))
+    }
+}
+
+/// Returns address of interface and arguments of DeBot callback handling `event`.
+pub(crate) fn encode_event(event: CollectionEvent) -> Result<(String, JsonValue), String> {
+    let object = pack(event.result).ok_or("failed to pack collection update")?;
+    Ok((
+        format!("{DEBOT_WC}:{ID}"),
+        json!({
+            "subscriptionId": event.subscription_id,
+            "object": object,
+        }),
+    ))
+}
+
+fn collection_name(collection_type: u8) -> Result<String, QueryStatus> {
+    match collection_type {
+        0 => Ok("accounts".to_owned()),
+        1 => Ok("messages".to_owned()),
+        2 => Ok("transactions".to_owned()),
+        3 => Ok("blocks".to_owned()),
+        _ => Err(QueryStatus::InvalidArgument),
+    }
+}
+
+fn parse_filter(filter: &str) -> Result<JsonValue, QueryStatus> {
+    if filter.trim().is_empty() {
+        return Ok(json!({}));
+    }
+    serde_json::from_str(filter).map_err(|_| QueryStatus::FilterError)
+}
+
+/// Adds condition selecting objects after `cursor` in `order_by` order to `filter`.
+fn cursor_filter(
+    mut filter: JsonValue,
+    order_by: &OrderBy,
+    cursor: JsonValue,
+) -> Result<JsonValue, QueryStatus> {
+    let op = match order_by.direction {
+        SortDirection::ASC => "gt",
+        SortDirection::DESC => "lt",
+    };
+    let mut node = &mut filter;
+    for field in order_by.path.split('.') {
+        node = node
+            .as_object_mut()
+            .ok_or(QueryStatus::FilterError)?
+            .entry(field)
+            .or_insert_with(|| json!({}));
+    }
+    node.as_object_mut()
+        .ok_or(QueryStatus::FilterError)?
+        .insert(op.to_owned(), cursor);
+    Ok(filter)
+}
+
+/// Converts dotted field path to GraphQL selection, e.g. "a.b" to "a { b }".
+fn selection(path: &str) -> String {
+    path.rsplit('.')
+        .fold(String::new(), |inner, field| match inner.is_empty() {
+            true => field.to_owned(),
+            false => format!("{field} {{ {inner} }}"),
+        })
+}
+
+#[async_trait::async_trait]
+impl DebotInterface for QueryV2Interface {
+    fn get_id(&self) -> String {
+        ID.to_string()
+    }
+
+    fn get_abi(&self) -> Abi {
+        Abi::Json(ABI.to_owned())
+    }
+
+    async fn call(&self, func: &str, args: &JsonValue) -> InterfaceResult {
+        match func {
+            "collection" => self.collection(args).await,
+            "aggregateCollection" => self.aggregate_collection(args).await,
+            "queryMessages" => self.query_messages(args).await,
+            "queryEvents" => self.query_events(args).await,
+            "queryAccountData" => self.query_account_data(args).await,
+            "subscribe" => self.subscribe(args).await,
+            "unsubscribe" => self.unsubscribe(args).await,
+            "waitForCollection" => self.wait_for_collection(args).await,
+            "query" => self.v1.query(args).await,
+            _ => Err(format!("function \"{func}\" is not implemented")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{cursor_filter, selection};
+    use crate::sdk_prelude::{OrderBy, SortDirection};
+    use serde_json::json;
+
+    #[test]
+    fn test_cursor_filter() {
+        let order_by = OrderBy {
+            path: "lt".to_owned(),
+            direction: SortDirection::DESC,
+        };
+        let filter = cursor_filter(json!({"lt": {"gt": "0x10"}}), &order_by, json!("0x20"));
+        assert_eq!(filter.ok(), Some(json!({"lt": {"gt": "0x10", "lt": "0x20"}})));
+
+        let order_by = OrderBy {
+            path: "block.seq_no".to_owned(),
+            direction: SortDirection::ASC,
+        };
+        let filter = cursor_filter(json!({}), &order_by, json!(5));
+        assert_eq!(filter.ok(), Some(json!({"block": {"seq_no": {"gt": 5}}})));
+        assert_eq!(selection("block.seq_no"), "block { seq_no }");
+        assert_eq!(selection("id"), "id");
+    }
+}
@@ -3,6 +3,9 @@ use crate::routines;
 use crate::sdk_prelude::*;
 use serde_json::Value;
 use crate::browser::BrowserRef;
+use crate::dengine::EMPTY_CELL;
+use ton_client::abi::{encode_boc, AbiParam, ParamsOfAbiEncodeBoc};
+use ton_client::tvm::{run_get, ParamsOfRunGet};
 
 const ABI: &str = r#"
 {
@@ -55,6 +58,34 @@ const ABI: &str = r#"
 				{"components":[{"name":"id","type":"address"},{"name":"data","type":"cell"}],"name":"accounts","type":"tuple[]"}
 			]
 		},
+		{
+			"name": "runGetMethod",
+			"inputs": [
+				{"name":"answerId","type":"uint32"},
+				{"name":"addr","type":"address"},
+				{"name":"abi","type":"string"},
+				{"name":"function","type":"string"},
+				{"name":"inputJson","type":"string"}
+			],
+			"outputs": [
+				{"name":"result","type":"uint32"},
+				{"name":"outputJson","type":"string"},
+				{"name":"output","type":"cell"}
+			]
+		},
+		{
+			"name": "runTvmGetter",
+			"inputs": [
+				{"name":"answerId","type":"uint32"},
+				{"name":"addr","type":"address"},
+				{"name":"method","type":"string"},
+				{"name":"stackJson","type":"string"}
+			],
+			"outputs": [
+				{"name":"result","type":"uint32"},
+				{"name":"stackJson","type":"string"}
+			]
+		},
 		{
 			"name": "encrypt",
 			"id": "0x1edf9b42",
@@ -519,6 +550,7 @@ impl SdkInterface {
         ))
     }
 
+    /// Byte-based, kept for compatibility. Strings interface counts characters.
     fn substring(&self, args: &Value) -> InterfaceResult {
         let answer_id = decode_answer_id(args)?;
         let src_str = get_arg(args, "str")?;
@@ -702,6 +734,126 @@ impl SdkInterface {
         Ok(res)
     }
 
+    /// Runs ABI get-method of account locally. Output is returned as JSON and
+    /// as a cell with values serialized according to function outputs.
+    async fn run_get_method(&self, args: &Value) -> InterfaceResult {
+        let answer_id = decode_answer_id(args)?;
+        let addr = get_arg(args, "addr")?;
+        let abi = get_arg(args, "abi")?;
+        let function = get_arg(args, "function")?;
+        let input = get_arg(args, "inputJson")?;
+
+        let (result, output_json, output) =
+            match self.run_abi_getter(addr, abi, &function, &input).await {
+                Ok((output_json, output)) => (0, output_json.to_string(), output),
+                Err(e) => {
+                    debug!(self.browser, "runGetMethod failed: {}", e);
+                    (e.code, String::new(), EMPTY_CELL.to_owned())
+                }
+            };
+        Ok((
+            answer_id,
+            json!({ "result": result, "outputJson": output_json, "output": output }),
+        ))
+    }
+
+    async fn run_abi_getter(
+        &self,
+        addr: String,
+        abi: String,
+        function: &str,
+        input: &str,
+    ) -> ClientResult<(Value, String)> {
+        let input = parse_json_arg(input, "input")?;
+        let abi_json: Value = serde_json::from_str(&abi).map_err(Error::invalid_json_params)?;
+        let outputs = abi_json["functions"]
+            .as_array()
+            .and_then(|funcs| funcs.iter().find(|f| f["name"] == function))
+            .map(|f| f["outputs"].clone())
+            .ok_or_else(|| {
+                Error::invalid_json_params(format!("function \"{function}\" not found in ABI"))
+            })?;
+        let outputs: Vec<AbiParam> =
+            serde_json::from_value(outputs).map_err(Error::invalid_json_params)?;
+        let state = DEngine::fetch_state_with_browser(self.browser.clone(), addr.clone())
+            .await
+            .map_err(Error::execute_failed)?;
+        let abi = Abi::Json(abi);
+        let message = encode_message(
+            self.ton.clone(),
+            ParamsOfEncodeMessage {
+                abi: abi.clone(),
+                address: Some(addr),
+                call_set: match input {
+                    Some(input) => CallSet::some_with_function_and_input(function, input),
+                    None => CallSet::some_with_function(function),
+                },
+                signer: Signer::None,
+                ..Default::default()
+            },
+        )
+        .await?
+        .message;
+        let output = run_tvm(
+            self.ton.clone(),
+            ParamsOfRunTvm {
+                account: state,
+                message,
+                abi: Some(abi),
+                ..Default::default()
+            },
+        )
+        .await?
+        .decoded
+        .and_then(|decoded| decoded.output)
+        .unwrap_or_else(|| json!({}));
+        let cell = encode_boc(
+            self.ton.clone(),
+            ParamsOfAbiEncodeBoc {
+                params: outputs,
+                data: output.clone(),
+                boc_cache: None,
+            },
+        )
+        .await?
+        .boc;
+        Ok((output, cell))
+    }
+
+    /// Runs TVM get-method of account by name. Stack is a JSON array in `run_get` format.
+    async fn run_tvm_getter(&self, args: &Value) -> InterfaceResult {
+        let answer_id = decode_answer_id(args)?;
+        let addr = get_arg(args, "addr")?;
+        let method = get_arg(args, "method")?;
+        let stack = get_arg(args, "stackJson")?;
+
+        let result = async {
+            let stack = parse_json_arg(&stack, "stack")?;
+            let account = DEngine::fetch_state_with_browser(self.browser.clone(), addr)
+                .await
+                .map_err(Error::execute_failed)?;
+            run_get(
+                self.ton.clone(),
+                ParamsOfRunGet {
+                    account,
+                    function_name: method,
+                    input: stack,
+                    ..Default::default()
+                },
+            )
+            .await
+        }
+        .await;
+        let (result, stack) = match result {
+            Ok(result) => (0, result.output.to_string()),
+            Err(e) => {
+                debug!(self.browser, "runTvmGetter failed: {}", e);
+                (e.code, String::new())
+            }
+        };
+        Ok((answer_id, json!({ "result": result, "stackJson": stack })))
+    }
+
     async fn get_signing_box_info(&self, args: &Value) -> InterfaceResult {
         let answer_id = decode_answer_id(args)?;
         let box_handle = get_num_arg::<u32>(args, "boxHandle")?;
@@ -733,6 +885,16 @@ impl SdkInterface {
     }
 }
 
+/// Parses JSON argument of get-method. Empty string means no argument.
+fn parse_json_arg(value: &str, name: &str) -> ClientResult<Option<Value>> {
+    if value.trim().is_empty() {
+        return Ok(None);
+    }
+    serde_json::from_str(value)
+        .map(Some)
+        .map_err(|e| Error::invalid_json_params(format!("invalid {name} JSON: {e}")))
+}
+
 #[async_trait::async_trait]
 impl DebotInterface for SdkInterface {
     fn get_id(&self) -> String {
@@ -748,6 +910,8 @@ impl DebotInterface for SdkInterface {
             "getBalance" => self.get_balance(args).await,
             "getAccountType" => self.get_account_type(args).await,
             "getAccountCodeHash" => self.get_account_code_hash(args).await,
+            "runGetMethod" => self.run_get_method(args).await,
+            "runTvmGetter" => self.run_tvm_getter(args).await,
 
             "mnemonicFromRandom" => self.mnemonic_from_random(args),
             "mnemonicDeriveSignKeys" => self.mnemonic_derive_sign_keys(args),
@@ -776,3 +940,21 @@ impl DebotInterface for SdkInterface {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::errors::ErrorCode;
+
+    #[test]
+    fn test_parse_json_arg() {
+        assert_eq!(parse_json_arg(" ", "input").unwrap(), None);
+        assert_eq!(
+            parse_json_arg(r#"{"a":1}"#, "input").unwrap(),
+            Some(json!({ "a": 1 }))
+        );
+        let err = parse_json_arg("{a:1}", "stack").unwrap_err();
+        assert_eq!(err.code, ErrorCode::DebotInvalidJsonParams as u32);
+        assert!(err.message.starts_with("invalid stack JSON"));
+    }
+}
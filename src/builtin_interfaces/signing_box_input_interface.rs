@@ -0,0 +1,136 @@
+use super::dinterface::{decode_answer_id, get_arg, DebotInterface, InterfaceResult};
+use crate::boxes::BoxRegistry;
+use crate::browser::BrowserRef;
+use crate::keys::KeyProviderRef;
+use crate::sdk_prelude::*;
+use serde_json::{json, Value};
+
+const ABI: &str = r#"
+{
+	"ABI version": 2,
+	"version": "2.2",
+	"header": ["time"],
+	"functions": [
+		{
+			"name": "get",
+			"id": "0x04895be9",
+			"inputs": [
+				{"name":"answerId","type":"uint32"},
+				{"name":"prompt","type":"string"},
+				{"name":"possiblePublicKeys","type":"uint256[]"}
+			],
+			"outputs": [
+				{"name":"handle","type":"uint32"}
+			]
+		},
+		{
+			"name": "constructor",
+			"id": "0x68b55f3f",
+			"inputs": [
+			],
+			"outputs": [
+			]
+		}
+	],
+	"data": [
+	],
+	"events": [
+	],
+	"fields": [
+		{"name":"_pubkey","type":"uint256"},
+		{"name":"_timestamp","type":"uint64"},
+		{"name":"_constructorFlag","type":"bool"}
+	]
+}
+"#;
+
+const SIGNING_BOX_INPUT_ID: &str =
+    "c13024e101c95e71afb1f5fa6d72f633d51e721de0320d73dfd6121a54e4d40a";
+
+pub struct SigningBoxInputInterface {
+    keys: KeyProviderRef,
+    browser: BrowserRef,
+    boxes: Arc<BoxRegistry>,
+}
+
+impl SigningBoxInputInterface {
+    pub fn new(keys: KeyProviderRef, browser: BrowserRef, boxes: Arc<BoxRegistry>) -> Self {
+        Self {
+            keys,
+            browser,
+            boxes,
+        }
+    }
+
+    async fn get(&self, args: &Value) -> InterfaceResult {
+        let answer_id = decode_answer_id(args)?;
+        let prompt = get_arg(args, "prompt")?;
+        let possible_keys = args["possiblePublicKeys"]
+            .as_array()
+            .ok_or_else(|| "\"possiblePublicKeys\" is invalid: must be array".to_string())?
+            .iter()
+            .map(|key| decode_public_key(key.as_str().unwrap_or_default()))
+            .collect::<Result<Vec<String>, String>>()?;
+        let public = select_key(&self.keys, &self.browser, prompt, &possible_keys).await?;
+        let handle = self
+            .keys
+            .get_signing_box(&public)
+            .await
+            .map_err(|e| format!("{e}"))?;
+        self.boxes.add_signing_box(&handle);
+        Ok((answer_id, json!({ "handle": handle.0 })))
+    }
+}
+
+/// Converts ABI uint256 value to public key in hex form.
+pub(super) fn decode_public_key(value: &str) -> Result<String, String> {
+    let key = decode_abi_bigint(value).map_err(|e| format!("invalid public key: {e}"))?;
+    Ok(format!("{key:064x}"))
+}
+
+/// Lists keys of key provider and requests user to choose one of them.
+///
+/// If `possible_keys` is not empty, only these keys are offered to user.
+pub(super) async fn select_key(
+    keys: &KeyProviderRef,
+    browser: &BrowserRef,
+    prompt: String,
+    possible_keys: &[String],
+) -> Result<String, String> {
+    let mut available = keys.list_keys().await.map_err(|e| format!("{e}"))?;
+    if !possible_keys.is_empty() {
+        available.retain(|key| possible_keys.contains(&key.to_lowercase()));
+    }
+    match available.len() {
+        0 => Err("no suitable keys found".to_string()),
+        1 => Ok(available.remove(0)),
+        _ => {
+            let public = browser
+                .select_key(prompt, available.clone())
+                .await
+                .map_err(|e| format!("{e}"))?;
+            if !available.contains(&public) {
+                return Err(format!("key {public} is not allowed"));
+            }
+            Ok(public)
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl DebotInterface for SigningBoxInputInterface {
+    fn get_id(&self) -> String {
+        SIGNING_BOX_INPUT_ID.to_string()
+    }
+
+    fn get_abi(&self) -> Abi {
+        Abi::Json(ABI.to_owned())
+    }
+
+    async fn call(&self, func: &str, args: &Value) -> InterfaceResult {
+        match func {
+            "get" => self.get(args).await,
+            _ => Err(format!("function \"{func}\" is not implemented")),
+        }
+    }
+}
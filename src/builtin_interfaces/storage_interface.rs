@@ -0,0 +1,261 @@
+use super::dinterface::{decode_answer_id, get_arg, DebotInterface, InterfaceResult};
+use crate::browser::{error, log, BrowserRef, LogLevel};
+use crate::dengine::load_ton_address;
+use crate::sdk_prelude::*;
+use crate::storage::StorageProviderRef;
+use serde_json::{json, Value};
+
+const ABI: &str = r#"
+{
+	"ABI version": 2,
+	"version": "2.2",
+	"header": ["time"],
+	"functions": [
+		{
+			"name": "get",
+			"inputs": [
+				{"name":"answerId","type":"uint32"},
+				{"name":"key","type":"string"}
+			],
+			"outputs": [
+				{"name":"status","type":"uint8"},
+				{"name":"exists","type":"bool"},
+				{"name":"value","type":"string"}
+			]
+		},
+		{
+			"name": "set",
+			"inputs": [
+				{"name":"answerId","type":"uint32"},
+				{"name":"key","type":"string"},
+				{"name":"value","type":"string"}
+			],
+			"outputs": [
+				{"name":"status","type":"uint8"}
+			]
+		},
+		{
+			"name": "remove",
+			"inputs": [
+				{"name":"answerId","type":"uint32"},
+				{"name":"key","type":"string"}
+			],
+			"outputs": [
+				{"name":"status","type":"uint8"}
+			]
+		},
+		{
+			"name": "keys",
+			"inputs": [
+				{"name":"answerId","type":"uint32"}
+			],
+			"outputs": [
+				{"name":"status","type":"uint8"},
+				{"name":"keys","type":"string[]"}
+			]
+		},
+		{
+			"name": "constructor",
+			"inputs": [
+			],
+			"outputs": [
+			]
+		}
+	],
+	"data": [
+	],
+	"events": [
+	],
+	"fields": [
+		{"name":"_pubkey","type":"uint256"},
+		{"name":"_timestamp","type":"uint64"},
+		{"name":"_constructorFlag","type":"bool"}
+	]
+}
+"#;
+
+const STORAGE_ID: &str = "a69c4dece144a46e40d430726395533d8f335a7d601d8ca292220b3a4a7faca4";
+
+const STATUS_SUCCESS: u8 = 0;
+const STATUS_QUOTA_EXCEEDED: u8 = 1;
+const STATUS_STORAGE_ERROR: u8 = 2;
+
+/// Storage interface. Key-value storage of DeBot kept between sessions.
+///
+/// Data is namespaced by DeBot address. Total size of keys and values is limited by quota.
+pub struct StorageInterface {
+    storage: StorageProviderRef,
+    namespace: String,
+    quota: usize,
+    browser: BrowserRef,
+}
+
+impl StorageInterface {
+    pub fn new(
+        storage: StorageProviderRef,
+        debot_addr: &str,
+        quota: u32,
+        browser: BrowserRef,
+    ) -> Self {
+        Self {
+            storage,
+            namespace: load_ton_address(debot_addr).unwrap_or_else(|_| debot_addr.to_owned()),
+            quota: quota as usize,
+            browser,
+        }
+    }
+
+    async fn get(&self, args: &Value) -> InterfaceResult {
+        let answer_id = decode_answer_id(args)?;
+        let key = get_arg(args, "key")?;
+        let (status, value) = match self.storage.get(&self.namespace, &key).await {
+            Ok(value) => (STATUS_SUCCESS, value),
+            Err(e) => (self.failed(e), None),
+        };
+        Ok((
+            answer_id,
+            json!({
+                "status": status,
+                "exists": value.is_some(),
+                "value": value.unwrap_or_default(),
+            }),
+        ))
+    }
+
+    async fn set(&self, args: &Value) -> InterfaceResult {
+        let answer_id = decode_answer_id(args)?;
+        let key = get_arg(args, "key")?;
+        let value = get_arg(args, "value")?;
+        let status = match self
+            .storage
+            .set_with_quota(&self.namespace, &key, value, self.quota)
+            .await
+        {
+            Ok(true) => STATUS_SUCCESS,
+            Ok(false) => STATUS_QUOTA_EXCEEDED,
+            Err(e) => self.failed(e),
+        };
+        Ok((answer_id, json!({ "status": status })))
+    }
+
+    async fn remove(&self, args: &Value) -> InterfaceResult {
+        let answer_id = decode_answer_id(args)?;
+        let key = get_arg(args, "key")?;
+        let status = match self.storage.remove(&self.namespace, &key).await {
+            Ok(()) => STATUS_SUCCESS,
+            Err(e) => self.failed(e),
+        };
+        Ok((answer_id, json!({ "status": status })))
+    }
+
+    async fn keys(&self, args: &Value) -> InterfaceResult {
+        let answer_id = decode_answer_id(args)?;
+        let (status, keys) = match self.storage.keys(&self.namespace).await {
+            Ok(keys) => (STATUS_SUCCESS, keys),
+            Err(e) => (self.failed(e), vec![]),
+        };
+        Ok((answer_id, json!({ "status": status, "keys": keys })))
+    }
+
+    fn failed(&self, err: ClientError) -> u8 {
+        error!(self.browser, "storage request failed: {}", err);
+        STATUS_STORAGE_ERROR
+    }
+}
+
+#[async_trait::async_trait]
+impl DebotInterface for StorageInterface {
+    fn get_id(&self) -> String {
+        STORAGE_ID.to_string()
+    }
+
+    fn get_abi(&self) -> Abi {
+        Abi::Json(ABI.to_owned())
+    }
+
+    async fn call(&self, func: &str, args: &Value) -> InterfaceResult {
+        match func {
+            "get" => self.get(args).await,
+            "set" => self.set(args).await,
+            "remove" => self.remove(args).await,
+            "keys" => self.keys(args).await,
+            _ => Err(format!("function \"{func}\" is not implemented")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::browser::BrowserCallbacks;
+    use crate::storage::MemoryStorageProvider;
+    use futures::executor::block_on;
+
+    const DEBOT1: &str = "0:1111111111111111111111111111111111111111111111111111111111111111";
+    const DEBOT2: &str = "0:2222222222222222222222222222222222222222222222222222222222222222";
+
+    struct Browser;
+
+    #[async_trait::async_trait]
+    impl BrowserCallbacks for Browser {
+        fn log(&self, _level: LogLevel, _msg: String) {}
+        async fn send(&self, _message: String) {}
+    }
+
+    fn interface(storage: &StorageProviderRef, debot_addr: &str, quota: u32) -> StorageInterface {
+        StorageInterface::new(storage.clone(), debot_addr, quota, Arc::new(Browser))
+    }
+
+    fn set(iface: &StorageInterface, key: &str, value: &str) -> Value {
+        let args = json!({ "answerId": "1", "key": key, "value": value });
+        let (answer_id, result) = block_on(iface.call("set", &args)).unwrap();
+        assert_eq!(answer_id, 1);
+        result["status"].clone()
+    }
+
+    fn get(iface: &StorageInterface, key: &str) -> Value {
+        block_on(iface.call("get", &json!({ "answerId": "2", "key": key })))
+            .unwrap()
+            .1
+    }
+
+    #[test]
+    fn test_quota_exceeded() {
+        let storage: StorageProviderRef = Arc::new(MemoryStorageProvider::new());
+        let iface = interface(&storage, DEBOT1, 8);
+        assert_eq!(set(&iface, "key", "12345"), json!(STATUS_SUCCESS));
+        assert_eq!(set(&iface, "k", "1"), json!(STATUS_QUOTA_EXCEEDED));
+        assert_eq!(get(&iface, "k")["exists"], json!(false));
+        assert_eq!(get(&iface, "key")["value"], json!("12345"));
+    }
+
+    #[test]
+    fn test_overwrite_at_quota() {
+        let storage: StorageProviderRef = Arc::new(MemoryStorageProvider::new());
+        let iface = interface(&storage, DEBOT1, 8);
+        assert_eq!(set(&iface, "key", "12345"), json!(STATUS_SUCCESS));
+        // Old value of the same key doesn't count against quota.
+        assert_eq!(set(&iface, "key", "54321"), json!(STATUS_SUCCESS));
+        assert_eq!(set(&iface, "key", "123456"), json!(STATUS_QUOTA_EXCEEDED));
+        assert_eq!(get(&iface, "key")["value"], json!("54321"));
+    }
+
+    #[test]
+    fn test_namespaces() {
+        let storage: StorageProviderRef = Arc::new(MemoryStorageProvider::new());
+        let first = interface(&storage, DEBOT1, 8);
+        let second = interface(&storage, DEBOT2, 8);
+        assert_eq!(set(&first, "key", "12345"), json!(STATUS_SUCCESS));
+        assert_eq!(get(&second, "key")["exists"], json!(false));
+        // Data of another DeBot doesn't count against quota.
+        assert_eq!(set(&second, "key", "abcde"), json!(STATUS_SUCCESS));
+        assert_eq!(get(&first, "key")["value"], json!("12345"));
+        assert_eq!(get(&second, "key")["value"], json!("abcde"));
+
+        let (_, keys) = block_on(first.call("keys", &json!({ "answerId": "3" }))).unwrap();
+        assert_eq!(keys["keys"], json!(["key"]));
+        block_on(first.call("remove", &json!({ "answerId": "4", "key": "key" }))).unwrap();
+        assert_eq!(get(&first, "key")["exists"], json!(false));
+        assert_eq!(get(&second, "key")["exists"], json!(true));
+    }
+}
@@ -0,0 +1,438 @@
+use super::dinterface::{decode_answer_id, get_arg, get_num_arg, DebotInterface, InterfaceResult};
+use serde_json::{json, Value};
+use ton_client::abi::Abi;
+
+const ABI: &str = r#"
+{
+	"ABI version": 2,
+	"version": "2.2",
+	"header": ["time"],
+	"functions": [
+		{
+			"name": "length",
+			"inputs": [
+				{"name":"answerId","type":"uint32"},
+				{"name":"str","type":"string"}
+			],
+			"outputs": [
+				{"name":"length","type":"uint32"}
+			]
+		},
+		{
+			"name": "substring",
+			"inputs": [
+				{"name":"answerId","type":"uint32"},
+				{"name":"str","type":"string"},
+				{"name":"start","type":"uint32"},
+				{"name":"count","type":"uint32"}
+			],
+			"outputs": [
+				{"name":"substr","type":"string"}
+			]
+		},
+		{
+			"name": "indexOf",
+			"inputs": [
+				{"name":"answerId","type":"uint32"},
+				{"name":"str","type":"string"},
+				{"name":"substr","type":"string"},
+				{"name":"from","type":"uint32"}
+			],
+			"outputs": [
+				{"name":"found","type":"bool"},
+				{"name":"index","type":"uint32"}
+			]
+		},
+		{
+			"name": "split",
+			"inputs": [
+				{"name":"answerId","type":"uint32"},
+				{"name":"str","type":"string"},
+				{"name":"separator","type":"string"}
+			],
+			"outputs": [
+				{"name":"parts","type":"string[]"}
+			]
+		},
+		{
+			"name": "join",
+			"inputs": [
+				{"name":"answerId","type":"uint32"},
+				{"name":"parts","type":"string[]"},
+				{"name":"separator","type":"string"}
+			],
+			"outputs": [
+				{"name":"str","type":"string"}
+			]
+		},
+		{
+			"name": "replace",
+			"inputs": [
+				{"name":"answerId","type":"uint32"},
+				{"name":"str","type":"string"},
+				{"name":"from","type":"string"},
+				{"name":"to","type":"string"}
+			],
+			"outputs": [
+				{"name":"str","type":"string"}
+			]
+		},
+		{
+			"name": "toUpper",
+			"inputs": [
+				{"name":"answerId","type":"uint32"},
+				{"name":"str","type":"string"}
+			],
+			"outputs": [
+				{"name":"str","type":"string"}
+			]
+		},
+		{
+			"name": "toLower",
+			"inputs": [
+				{"name":"answerId","type":"uint32"},
+				{"name":"str","type":"string"}
+			],
+			"outputs": [
+				{"name":"str","type":"string"}
+			]
+		},
+		{
+			"name": "trim",
+			"inputs": [
+				{"name":"answerId","type":"uint32"},
+				{"name":"str","type":"string"}
+			],
+			"outputs": [
+				{"name":"str","type":"string"}
+			]
+		},
+		{
+			"name": "padStart",
+			"inputs": [
+				{"name":"answerId","type":"uint32"},
+				{"name":"str","type":"string"},
+				{"name":"length","type":"uint32"},
+				{"name":"pad","type":"string"}
+			],
+			"outputs": [
+				{"name":"str","type":"string"}
+			]
+		},
+		{
+			"name": "formatNumber",
+			"inputs": [
+				{"name":"answerId","type":"uint32"},
+				{"name":"value","type":"int256"},
+				{"name":"decimals","type":"uint8"},
+				{"name":"groupSeparator","type":"string"},
+				{"name":"decimalSeparator","type":"string"}
+			],
+			"outputs": [
+				{"name":"str","type":"string"}
+			]
+		},
+		{
+			"name": "parseNumber",
+			"inputs": [
+				{"name":"answerId","type":"uint32"},
+				{"name":"str","type":"string"},
+				{"name":"decimals","type":"uint8"},
+				{"name":"groupSeparator","type":"string"},
+				{"name":"decimalSeparator","type":"string"}
+			],
+			"outputs": [
+				{"name":"valid","type":"bool"},
+				{"name":"value","type":"int256"}
+			]
+		},
+		{
+			"name": "constructor",
+			"inputs": [
+			],
+			"outputs": [
+			]
+		}
+	],
+	"data": [
+	],
+	"events": [
+	],
+	"fields": [
+		{"name":"_pubkey","type":"uint256"},
+		{"name":"_timestamp","type":"uint64"},
+		{"name":"_constructorFlag","type":"bool"}
+	]
+}
+"#;
+
+const STRINGS_ID: &str = "31058086b35a14d971dc55cd96f5d82c57d22aa8ab462172d66677ede215a25d";
+
+/// Maximal number of digits which always fits into int256.
+const MAX_INT256_DIGITS: usize = 76;
+
+/// Strings interface. Indexes and lengths are counted in unicode characters, not bytes.
+pub struct StringsInterface {}
+
+impl StringsInterface {
+    pub fn new() -> Self {
+        Self {}
+    }
+
+    fn length(&self, args: &Value) -> InterfaceResult {
+        let answer_id = decode_answer_id(args)?;
+        let s = get_arg(args, "str")?;
+        Ok((answer_id, json!({ "length": s.chars().count() })))
+    }
+
+    fn substring(&self, args: &Value) -> InterfaceResult {
+        let answer_id = decode_answer_id(args)?;
+        let s = get_arg(args, "str")?;
+        let start = get_num_arg::<u32>(args, "start")? as usize;
+        let count = get_num_arg::<u32>(args, "count")? as usize;
+        Ok((answer_id, json!({ "substr": substring(&s, start, count) })))
+    }
+
+    fn index_of(&self, args: &Value) -> InterfaceResult {
+        let answer_id = decode_answer_id(args)?;
+        let s = get_arg(args, "str")?;
+        let substr = get_arg(args, "substr")?;
+        let from = get_num_arg::<u32>(args, "from")? as usize;
+        let index = index_of(&s, &substr, from);
+        Ok((
+            answer_id,
+            json!({ "found": index.is_some(), "index": index.unwrap_or_default() }),
+        ))
+    }
+
+    fn split(&self, args: &Value) -> InterfaceResult {
+        let answer_id = decode_answer_id(args)?;
+        let s = get_arg(args, "str")?;
+        let separator = get_arg(args, "separator")?;
+        let parts: Vec<String> = if separator.is_empty() {
+            s.chars().map(String::from).collect()
+        } else {
+            s.split(separator.as_str()).map(String::from).collect()
+        };
+        Ok((answer_id, json!({ "parts": parts })))
+    }
+
+    fn join(&self, args: &Value) -> InterfaceResult {
+        let answer_id = decode_answer_id(args)?;
+        let parts = args["parts"]
+            .as_array()
+            .ok_or_else(|| "\"parts\" not found".to_string())?
+            .iter()
+            .map(|p| p.as_str().ok_or_else(|| "\"parts\" is invalid".to_string()))
+            .collect::<Result<Vec<_>, _>>()?;
+        let separator = get_arg(args, "separator")?;
+        Ok((answer_id, json!({ "str": parts.join(&separator) })))
+    }
+
+    fn replace(&self, args: &Value) -> InterfaceResult {
+        let answer_id = decode_answer_id(args)?;
+        let s = get_arg(args, "str")?;
+        let from = get_arg(args, "from")?;
+        let to = get_arg(args, "to")?;
+        let replaced = if from.is_empty() {
+            s
+        } else {
+            s.replace(&from, &to)
+        };
+        Ok((answer_id, json!({ "str": replaced })))
+    }
+
+    fn convert(&self, args: &Value, f: impl Fn(&str) -> String) -> InterfaceResult {
+        let answer_id = decode_answer_id(args)?;
+        let s = get_arg(args, "str")?;
+        Ok((answer_id, json!({ "str": f(&s) })))
+    }
+
+    fn pad_start(&self, args: &Value) -> InterfaceResult {
+        let answer_id = decode_answer_id(args)?;
+        let s = get_arg(args, "str")?;
+        let length = get_num_arg::<u32>(args, "length")? as usize;
+        let pad = get_arg(args, "pad")?;
+        Ok((answer_id, json!({ "str": pad_start(&s, length, &pad) })))
+    }
+
+    fn format_number(&self, args: &Value) -> InterfaceResult {
+        let answer_id = decode_answer_id(args)?;
+        let value = get_arg(args, "value")?;
+        let decimals = get_num_arg::<u8>(args, "decimals")? as usize;
+        let group_separator = get_arg(args, "groupSeparator")?;
+        let decimal_separator = get_arg(args, "decimalSeparator")?;
+        let formatted = format_number(&value, decimals, &group_separator, &decimal_separator)
+            .ok_or_else(|| "\"value\" is invalid".to_string())?;
+        Ok((answer_id, json!({ "str": formatted })))
+    }
+
+    fn parse_number(&self, args: &Value) -> InterfaceResult {
+        let answer_id = decode_answer_id(args)?;
+        let s = get_arg(args, "str")?;
+        let decimals = get_num_arg::<u8>(args, "decimals")? as usize;
+        let group_separator = get_arg(args, "groupSeparator")?;
+        let decimal_separator = get_arg(args, "decimalSeparator")?;
+        let value = parse_number(&s, decimals, &group_separator, &decimal_separator);
+        Ok((
+            answer_id,
+            json!({
+                "valid": value.is_some(),
+                "value": value.unwrap_or_else(|| "0".to_owned()),
+            }),
+        ))
+    }
+}
+
+/// Returns `count` characters starting from character `start`. Out of range parts are cut off.
+fn substring(s: &str, start: usize, count: usize) -> String {
+    s.chars().skip(start).take(count).collect()
+}
+
+/// Returns character index of the first occurrence of `substr` at or after character `from`.
+fn index_of(s: &str, substr: &str, from: usize) -> Option<usize> {
+    let (byte_from, _) = s.char_indices().chain([(s.len(), ' ')]).nth(from)?;
+    let pos = s[byte_from..].find(substr)?;
+    Some(from + s[byte_from..byte_from + pos].chars().count())
+}
+
+/// Pads string from the start by repeating `pad` until it is `length` characters long.
+fn pad_start(s: &str, length: usize, pad: &str) -> String {
+    let missing = length.saturating_sub(s.chars().count());
+    if missing == 0 || pad.is_empty() {
+        return s.to_owned();
+    }
+    pad.chars().cycle().take(missing).chain(s.chars()).collect()
+}
+
+/// Formats integer `value` as a number with `decimals` fractional digits.
+/// Integer part digits are grouped by three.
+fn format_number(
+    value: &str,
+    decimals: usize,
+    group_separator: &str,
+    decimal_separator: &str,
+) -> Option<String> {
+    let (sign, digits) = match value.strip_prefix('-') {
+        Some(digits) => ("-", digits),
+        None => ("", value),
+    };
+    if digits.is_empty() || !digits.bytes().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    let digits = format!("{digits:0>width$}", width = decimals + 1);
+    let (int_part, frac_part) = digits.split_at(digits.len() - decimals);
+    let int_part = int_part.trim_start_matches('0');
+    let int_part = if int_part.is_empty() { "0" } else { int_part };
+    let mut result = sign.to_owned();
+    for (i, c) in int_part.chars().enumerate() {
+        if i > 0 && (int_part.len() - i) % 3 == 0 {
+            result.push_str(group_separator);
+        }
+        result.push(c);
+    }
+    if decimals > 0 {
+        result.push_str(decimal_separator);
+        result.push_str(frac_part);
+    }
+    Some(result)
+}
+
+/// Parses number formatted by `format_number` into integer with `decimals` fractional digits.
+/// Fractional part may be shorter than `decimals`, but not longer.
+fn parse_number(
+    s: &str,
+    decimals: usize,
+    group_separator: &str,
+    decimal_separator: &str,
+) -> Option<String> {
+    let s = s.trim();
+    let (sign, s) = match s.strip_prefix('-') {
+        Some(s) => ("-", s),
+        None => ("", s),
+    };
+    let (int_part, frac_part) = match decimal_separator {
+        "" => (s, ""),
+        sep => s.split_once(sep).unwrap_or((s, "")),
+    };
+    let int_part = match group_separator {
+        "" => int_part.to_owned(),
+        sep => int_part.replace(sep, ""),
+    };
+    let is_digits = |v: &str| v.bytes().all(|c| c.is_ascii_digit());
+    if int_part.is_empty() || !is_digits(&int_part) || !is_digits(frac_part) {
+        return None;
+    }
+    if frac_part.len() > decimals {
+        return None;
+    }
+    let digits = format!("{int_part}{frac_part:0<decimals$}");
+    let digits = digits.trim_start_matches('0');
+    if digits.len() > MAX_INT256_DIGITS {
+        return None;
+    }
+    Some(match digits {
+        "" => "0".to_owned(),
+        _ => format!("{sign}{digits}"),
+    })
+}
+
+#[async_trait::async_trait]
+impl DebotInterface for StringsInterface {
+    fn get_id(&self) -> String {
+        STRINGS_ID.to_string()
+    }
+
+    fn get_abi(&self) -> Abi {
+        Abi::Json(ABI.to_owned())
+    }
+
+    async fn call(&self, func: &str, args: &Value) -> InterfaceResult {
+        match func {
+            "length" => self.length(args),
+            "substring" => self.substring(args),
+            "indexOf" => self.index_of(args),
+            "split" => self.split(args),
+            "join" => self.join(args),
+            "replace" => self.replace(args),
+            "toUpper" => self.convert(args, str::to_uppercase),
+            "toLower" => self.convert(args, str::to_lowercase),
+            "trim" => self.convert(args, |s| s.trim().to_owned()),
+            "padStart" => self.pad_start(args),
+            "formatNumber" => self.format_number(args),
+            "parseNumber" => self.parse_number(args),
+            _ => Err(format!("function \"{func}\" is not implemented")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{format_number, index_of, pad_start, parse_number, substring};
+
+    #[test]
+    fn test_unicode_strings() {
+        assert_eq!(substring("Привет, мир", 8, 3), "мир");
+        assert_eq!(substring("你好世界", 2, 10), "世界");
+        assert_eq!(substring("abc", 5, 1), "");
+        assert_eq!(index_of("мир, мир", "мир", 1), Some(5));
+        assert_eq!(index_of("你好", "", 2), Some(2));
+        assert_eq!(index_of("你好", "好", 3), None);
+        assert_eq!(pad_start("7", 3, "0"), "007");
+        assert_eq!(pad_start("ё", 4, "аб"), "абаё");
+    }
+
+    #[test]
+    fn test_numbers() {
+        assert_eq!(format_number("1234567890", 9, " ", ".").unwrap(), "1.234567890");
+        assert_eq!(format_number("-123456789", 0, ",", ".").unwrap(), "-123,456,789");
+        assert_eq!(format_number("5", 3, ",", ".").unwrap(), "0.005");
+        assert!(format_number("1e5", 0, ",", ".").is_none());
+        assert_eq!(parse_number("1 234.5", 3, " ", ".").unwrap(), "1234500");
+        assert_eq!(parse_number("-0,005", 3, " ", ",").unwrap(), "-5");
+        assert_eq!(parse_number("0.000", 3, "", ".").unwrap(), "0");
+        assert!(parse_number("1.2345", 3, "", ".").is_none());
+        assert!(parse_number("12a", 0, "", ".").is_none());
+        assert!(parse_number(".5", 1, "", ".").is_none());
+    }
+}
@@ -0,0 +1,299 @@
+use super::dinterface::{
+    decode_answer_id, get_arg, get_bool_arg, parse_decimal, DebotInterface, InterfaceResult,
+    MAX_INPUT_ATTEMPTS,
+};
+use crate::browser::{BrowserRef, LogLevel};
+use crate::sdk_prelude::*;
+use serde_json::{json, Value};
+use ton_client::abi::{decode_boc, AbiParam, ParamsOfDecodeBoc};
+
+const ABI: &str = r#"
+{
+	"ABI version": 2,
+	"version": "2.2",
+	"header": ["time"],
+	"functions": [
+		{
+			"name": "input",
+			"id": "0x3955f72f",
+			"inputs": [
+				{"name":"answerId","type":"uint32"},
+				{"name":"prompt","type":"string"},
+				{"name":"multiline","type":"bool"}
+			],
+			"outputs": [
+				{"name":"value","type":"string"}
+			]
+		},
+		{
+			"name": "print",
+			"id": "0x0ce649c2",
+			"inputs": [
+				{"name":"answerId","type":"uint32"},
+				{"name":"message","type":"string"}
+			],
+			"outputs": [
+			]
+		},
+		{
+			"name": "printf",
+			"id": "0x36a926ce",
+			"inputs": [
+				{"name":"answerId","type":"uint32"},
+				{"name":"fmt","type":"string"},
+				{"name":"fargs","type":"cell"}
+			],
+			"outputs": [
+			]
+		},
+		{
+			"name": "inputInt",
+			"inputs": [
+				{"name":"answerId","type":"uint32"},
+				{"name":"prompt","type":"string"}
+			],
+			"outputs": [
+				{"name":"value","type":"int256"}
+			]
+		},
+		{
+			"name": "inputUint",
+			"inputs": [
+				{"name":"answerId","type":"uint32"},
+				{"name":"prompt","type":"string"}
+			],
+			"outputs": [
+				{"name":"value","type":"uint256"}
+			]
+		},
+		{
+			"name": "inputTons",
+			"inputs": [
+				{"name":"answerId","type":"uint32"},
+				{"name":"prompt","type":"string"}
+			],
+			"outputs": [
+				{"name":"value","type":"uint128"}
+			]
+		},
+		{
+			"name": "inputBoolean",
+			"inputs": [
+				{"name":"answerId","type":"uint32"},
+				{"name":"prompt","type":"string"}
+			],
+			"outputs": [
+				{"name":"value","type":"bool"}
+			]
+		},
+		{
+			"name": "constructor",
+			"id": "0x68b55f3f",
+			"inputs": [
+			],
+			"outputs": [
+			]
+		}
+	],
+	"data": [
+	],
+	"events": [
+	],
+	"fields": [
+		{"name":"_pubkey","type":"uint256"},
+		{"name":"_timestamp","type":"uint64"},
+		{"name":"_constructorFlag","type":"bool"}
+	]
+}
+"#;
+
+const TERMINAL_ID: &str = "8796536366ee21852db56dccb60bc564598b618c865fc50c8b1ab740bba128e3";
+
+const TON_DECIMALS: u8 = 9;
+
+pub struct TerminalInterface {
+    ton: TonClient,
+    browser: BrowserRef,
+}
+
+impl TerminalInterface {
+    pub fn new(ton: TonClient, browser: BrowserRef) -> Self {
+        Self { ton, browser }
+    }
+
+    async fn input(&self, args: &Value) -> InterfaceResult {
+        let answer_id = decode_answer_id(args)?;
+        let prompt = get_arg(args, "prompt")?;
+        let multiline = get_bool_arg(args, "multiline")?;
+        let value = self.input_text(prompt, multiline).await?;
+        Ok((answer_id, json!({ "value": value })))
+    }
+
+    async fn print(&self, args: &Value) -> InterfaceResult {
+        let answer_id = decode_answer_id(args)?;
+        let message = get_arg(args, "message")?;
+        self.browser
+            .print(message)
+            .await
+            .map_err(|e| format!("{e}"))?;
+        Ok((answer_id, json!({})))
+    }
+
+    /// Prints formatted string. Each placeholder in `fmt` contains ABI type of
+    /// the next argument serialized in `fargs` cell, e.g. "{uint32}" or "{address}".
+    async fn printf(&self, args: &Value) -> InterfaceResult {
+        let answer_id = decode_answer_id(args)?;
+        let fmt = get_arg(args, "fmt")?;
+        let fargs = get_arg(args, "fargs")?;
+        let message = self.format(&fmt, fargs).await?;
+        self.browser
+            .print(message)
+            .await
+            .map_err(|e| format!("{e}"))?;
+        Ok((answer_id, json!({})))
+    }
+
+    async fn input_int(&self, args: &Value) -> InterfaceResult {
+        let answer_id = decode_answer_id(args)?;
+        let prompt = get_arg(args, "prompt")?;
+        let value = self
+            .input_checked(prompt, |value| {
+                decode_abi_bigint(value).map_err(|e| format!("invalid integer \"{value}\": {e}"))
+            })
+            .await?;
+        Ok((answer_id, json!({ "value": value.to_string() })))
+    }
+
+    async fn input_uint(&self, args: &Value) -> InterfaceResult {
+        let answer_id = decode_answer_id(args)?;
+        let prompt = get_arg(args, "prompt")?;
+        let value = self
+            .input_checked(prompt, |value| {
+                let number = decode_abi_bigint(value)
+                    .map_err(|e| format!("invalid unsigned integer \"{value}\": {e}"))?
+                    .to_string();
+                if number.starts_with('-') {
+                    return Err(format!("invalid unsigned integer \"{value}\""));
+                }
+                Ok(number)
+            })
+            .await?;
+        Ok((answer_id, json!({ "value": value })))
+    }
+
+    async fn input_tons(&self, args: &Value) -> InterfaceResult {
+        let answer_id = decode_answer_id(args)?;
+        let prompt = get_arg(args, "prompt")?;
+        let value = self
+            .input_checked(prompt, |value| parse_decimal(value, TON_DECIMALS))
+            .await?;
+        Ok((answer_id, json!({ "value": value.to_string() })))
+    }
+
+    async fn input_boolean(&self, args: &Value) -> InterfaceResult {
+        let answer_id = decode_answer_id(args)?;
+        let prompt = get_arg(args, "prompt")?;
+        let value = self
+            .input_checked(prompt, |value| match value.to_lowercase().as_str() {
+                "y" | "yes" | "true" => Ok(true),
+                "n" | "no" | "false" => Ok(false),
+                _ => Err(format!("invalid boolean \"{value}\"")),
+            })
+            .await?;
+        Ok((answer_id, json!({ "value": value })))
+    }
+
+    /// Asks user for a value until it is valid, at most `MAX_INPUT_ATTEMPTS` times.
+    async fn input_checked<T>(
+        &self,
+        prompt: String,
+        check: impl Fn(&str) -> Result<T, String>,
+    ) -> Result<T, String> {
+        let mut attempt = 1;
+        loop {
+            let value = self.input_text(prompt.clone(), false).await?;
+            match check(value.trim()) {
+                Ok(value) => return Ok(value),
+                Err(e) if attempt < MAX_INPUT_ATTEMPTS => {
+                    self.browser.log(LogLevel::User, format!("{e}. Try again."));
+                    attempt += 1;
+                }
+                Err(e) => return Err(e),
+            }
+        }
+    }
+
+    async fn input_text(&self, prompt: String, multiline: bool) -> Result<String, String> {
+        self.browser
+            .input_text(prompt, multiline)
+            .await
+            .map_err(|e| format!("{e}"))
+    }
+
+    async fn format(&self, fmt: &str, fargs: String) -> Result<String, String> {
+        let mut pieces = vec![];
+        let mut params = vec![];
+        let mut rest = fmt;
+        while let Some(start) = rest.find('{') {
+            let end = rest[start..]
+                .find('}')
+                .ok_or_else(|| format!("invalid format string \"{fmt}\": unclosed placeholder"))?;
+            pieces.push(rest[..start].to_owned());
+            let param: AbiParam = serde_json::from_value(json!({
+                "name": format!("arg{}", params.len()),
+                "type": &rest[start + 1..start + end],
+            }))
+            .map_err(|e| format!("invalid placeholder type: {e}"))?;
+            params.push(param);
+            rest = &rest[start + end + 1..];
+        }
+        if params.is_empty() {
+            return Ok(fmt.to_owned());
+        }
+        let decoded = decode_boc(
+            self.ton.clone(),
+            ParamsOfDecodeBoc {
+                params,
+                boc: fargs,
+                allow_partial: true,
+            },
+        )
+        .await
+        .map_err(|e| format!("failed to decode printf arguments: {e}"))?
+        .data;
+        let mut message = String::new();
+        for (i, piece) in pieces.iter().enumerate() {
+            message.push_str(piece);
+            match &decoded[format!("arg{i}")] {
+                Value::String(s) => message.push_str(s),
+                other => message.push_str(&other.to_string()),
+            }
+        }
+        message.push_str(rest);
+        Ok(message)
+    }
+}
+
+#[async_trait::async_trait]
+impl DebotInterface for TerminalInterface {
+    fn get_id(&self) -> String {
+        TERMINAL_ID.to_string()
+    }
+
+    fn get_abi(&self) -> Abi {
+        Abi::Json(ABI.to_owned())
+    }
+
+    async fn call(&self, func: &str, args: &Value) -> InterfaceResult {
+        match func {
+            "input" => self.input(args).await,
+            "print" => self.print(args).await,
+            "printf" => self.printf(args).await,
+            "inputInt" => self.input_int(args).await,
+            "inputUint" => self.input_uint(args).await,
+            "inputTons" => self.input_tons(args).await,
+            "inputBoolean" => self.input_boolean(args).await,
+            _ => Err(format!("function \"{func}\" is not implemented")),
+        }
+    }
+}
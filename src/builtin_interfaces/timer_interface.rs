@@ -0,0 +1,175 @@
+use super::dinterface::{decode_answer_id, get_num_arg, DebotInterface, InterfaceResult};
+use crate::helpers::{now_ms, spawn};
+use crate::sdk_prelude::*;
+use crate::subscriptions::Subscriptions;
+use futures_timer::Delay;
+use serde_json::{json, Value};
+use std::time::Duration;
+
+const ABI: &str = r#"
+{
+	"ABI version": 2,
+	"version": "2.2",
+	"header": ["time"],
+	"functions": [
+		{
+			"name": "sleep",
+			"inputs": [
+				{"name":"answerId","type":"uint32"},
+				{"name":"ms","type":"uint32"}
+			],
+			"outputs": [
+			]
+		},
+		{
+			"name": "setTimeout",
+			"inputs": [
+				{"name":"answerId","type":"uint32"},
+				{"name":"ms","type":"uint32"},
+				{"name":"id","type":"uint32"}
+			],
+			"outputs": [
+				{"name":"id","type":"uint32"}
+			]
+		},
+		{
+			"name": "now",
+			"inputs": [
+				{"name":"answerId","type":"uint32"}
+			],
+			"outputs": [
+				{"name":"ms","type":"uint64"}
+			]
+		},
+		{
+			"name": "constructor",
+			"inputs": [
+			],
+			"outputs": [
+			]
+		}
+	],
+	"data": [
+	],
+	"events": [
+	],
+	"fields": [
+		{"name":"_pubkey","type":"uint256"},
+		{"name":"_timestamp","type":"uint64"},
+		{"name":"_constructorFlag","type":"bool"}
+	]
+}
+"#;
+
+const TIMER_ID: &str = "cdeec2b374c18dd4b59abc6d8c7001c4b163b3261ae62ac84b15560490f037a0";
+
+/// Timer interface. `sleep` and `setTimeout` answer after a delay.
+///
+/// Delayed answers are queued as engine events, so engine is not locked while waiting.
+/// `sleep` and `setTimeout` fail if engine events are not dispatched (see `DEngine::events`).
+pub struct TimerInterface {
+    subscriptions: Arc<Subscriptions>,
+}
+
+impl TimerInterface {
+    pub(crate) fn new(subscriptions: Arc<Subscriptions>) -> Self {
+        Self { subscriptions }
+    }
+
+    fn sleep(&self, args: &Value) -> InterfaceResult {
+        let answer_id = decode_answer_id(args)?;
+        let ms = get_num_arg::<u32>(args, "ms")?;
+        self.answer_later(ms, answer_id, json!({}))?;
+        Ok((answer_id, Value::Null))
+    }
+
+    fn set_timeout(&self, args: &Value) -> InterfaceResult {
+        let answer_id = decode_answer_id(args)?;
+        let ms = get_num_arg::<u32>(args, "ms")?;
+        let id = get_num_arg::<u32>(args, "id")?;
+        self.answer_later(ms, answer_id, json!({ "id": id }))?;
+        Ok((answer_id, Value::Null))
+    }
+
+    fn now(&self, args: &Value) -> InterfaceResult {
+        let answer_id = decode_answer_id(args)?;
+        Ok((answer_id, json!({ "ms": now_ms() })))
+    }
+
+    /// Queues answer after `ms` milliseconds.
+    fn answer_later(&self, ms: u32, answer_id: u32, params: Value) -> Result<(), String> {
+        if !self.subscriptions.is_listened() {
+            return Err("engine events are not dispatched, answer can't be delivered".to_owned());
+        }
+        let subscriptions = self.subscriptions.clone();
+        let spawned = spawn(async move {
+            Delay::new(Duration::from_millis(ms as u64)).await;
+            subscriptions.push_answer(format!("{DEBOT_WC}:{TIMER_ID}"), answer_id, params);
+        });
+        if !spawned {
+            return Err("async runtime is not available".to_owned());
+        }
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl DebotInterface for TimerInterface {
+    fn get_id(&self) -> String {
+        TIMER_ID.to_string()
+    }
+
+    fn get_abi(&self) -> Abi {
+        Abi::Json(ABI.to_owned())
+    }
+
+    async fn call(&self, func: &str, args: &Value) -> InterfaceResult {
+        match func {
+            "sleep" => self.sleep(args),
+            "setTimeout" => self.set_timeout(args),
+            "now" => self.now(args),
+            _ => Err(format!("function \"{func}\" is not implemented")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::subscriptions::DebotEvent;
+
+    #[test]
+    fn test_sleep_without_listener() {
+        let iface = TimerInterface::new(Arc::new(Subscriptions::new()));
+        let args = json!({ "answerId": "1", "ms": "0" });
+        let rt = tokio::runtime::Builder::new_current_thread().build().unwrap();
+        assert!(rt.block_on(iface.call("sleep", &args)).is_err());
+    }
+
+    #[test]
+    fn test_set_timeout_answer() {
+        let subscriptions = Arc::new(Subscriptions::new());
+        let events = subscriptions.events();
+        let iface = TimerInterface::new(subscriptions);
+        let args = json!({ "answerId": "7", "ms": "10", "id": "42" });
+        let rt = tokio::runtime::Builder::new_current_thread().build().unwrap();
+        let event = rt.block_on(async {
+            let (answer_id, result) = iface.call("setTimeout", &args).await.unwrap();
+            assert_eq!(answer_id, 7);
+            assert_eq!(result, Value::Null);
+            events.next().await
+        });
+        match event {
+            Some(DebotEvent::Answer {
+                source,
+                answer_id,
+                params,
+            }) => {
+                assert_eq!(source, format!("{DEBOT_WC}:{TIMER_ID}"));
+                assert_eq!(answer_id, 7);
+                assert_eq!(params, json!({ "id": 42 }));
+            }
+            _ => panic!("answer expected"),
+        }
+    }
+}
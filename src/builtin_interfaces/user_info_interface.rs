@@ -0,0 +1,138 @@
+use super::dinterface::{decode_answer_id, DebotInterface, InterfaceResult, ZERO_ADDRESS};
+use crate::boxes::BoxRegistry;
+use crate::browser::BrowserRef;
+use crate::sdk_prelude::*;
+use api_derive::ApiType;
+use serde_derive::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+const ABI: &str = r#"
+{
+	"ABI version": 2,
+	"version": "2.2",
+	"header": ["time"],
+	"functions": [
+		{
+			"name": "getAccount",
+			"inputs": [
+				{"name":"answerId","type":"uint32"}
+			],
+			"outputs": [
+				{"name":"value","type":"address"}
+			]
+		},
+		{
+			"name": "getPublicKey",
+			"inputs": [
+				{"name":"answerId","type":"uint32"}
+			],
+			"outputs": [
+				{"name":"value","type":"uint256"}
+			]
+		},
+		{
+			"name": "getSigningBox",
+			"inputs": [
+				{"name":"answerId","type":"uint32"}
+			],
+			"outputs": [
+				{"name":"handle","type":"uint32"}
+			]
+		},
+		{
+			"name": "constructor",
+			"inputs": [
+			],
+			"outputs": [
+			]
+		}
+	],
+	"data": [
+	],
+	"events": [
+	],
+	"fields": [
+		{"name":"_pubkey","type":"uint256"},
+		{"name":"_timestamp","type":"uint64"},
+		{"name":"_constructorFlag","type":"bool"}
+	]
+}
+"#;
+
+const USER_INFO_ID: &str = "a56115147709ed3437efb89460b94a120b7fe94379c795d1ebb0435a847ee580";
+
+/// User identity returned to DeBots by UserInfo interface.
+#[derive(Serialize, Deserialize, Clone, Debug, ApiType, Default)]
+pub struct UserProfile {
+    /// Default user wallet address.
+    pub account: Option<String>,
+    /// User public key encoded as hex string.
+    pub public_key: Option<String>,
+    /// Signing box of user key. Owned by browser.
+    /// If not set, engine requests it from browser.
+    pub signing_box: Option<SigningBoxHandle>,
+}
+
+pub struct UserInfoInterface {
+    profile: UserProfile,
+    browser: BrowserRef,
+    boxes: Arc<BoxRegistry>,
+}
+
+impl UserInfoInterface {
+    pub fn new(profile: UserProfile, browser: BrowserRef, boxes: Arc<BoxRegistry>) -> Self {
+        Self {
+            profile,
+            browser,
+            boxes,
+        }
+    }
+
+    fn get_account(&self, args: &Value) -> InterfaceResult {
+        let answer_id = decode_answer_id(args)?;
+        let value = self.profile.account.as_deref().unwrap_or(ZERO_ADDRESS);
+        Ok((answer_id, json!({ "value": value })))
+    }
+
+    fn get_public_key(&self, args: &Value) -> InterfaceResult {
+        let answer_id = decode_answer_id(args)?;
+        let value = match self.profile.public_key.as_ref() {
+            Some(key) => format!("0x{key}"),
+            None => "0".to_string(),
+        };
+        Ok((answer_id, json!({ "value": value })))
+    }
+
+    async fn get_signing_box(&self, args: &Value) -> InterfaceResult {
+        let answer_id = decode_answer_id(args)?;
+        let handle = match self.profile.signing_box.clone() {
+            Some(handle) => handle,
+            None => {
+                let handle = self.browser.get_signing_box().await?;
+                self.boxes.add_signing_box(&handle);
+                handle
+            }
+        };
+        Ok((answer_id, json!({ "handle": handle.0 })))
+    }
+}
+
+#[async_trait::async_trait]
+impl DebotInterface for UserInfoInterface {
+    fn get_id(&self) -> String {
+        USER_INFO_ID.to_string()
+    }
+
+    fn get_abi(&self) -> Abi {
+        Abi::Json(ABI.to_owned())
+    }
+
+    async fn call(&self, func: &str, args: &Value) -> InterfaceResult {
+        match func {
+            "getAccount" => self.get_account(args),
+            "getPublicKey" => self.get_public_key(args),
+            "getSigningBox" => self.get_signing_box(args).await,
+            _ => Err(format!("function \"{func}\" is not implemented")),
+        }
+    }
+}
@@ -1,3 +1,4 @@
+use crate::boxes::BoxRegistry;
 use crate::browser::WaitForTransactionParams;
 use crate::common::*;
 use crate::sdk_prelude::*;
@@ -5,6 +6,15 @@ const SUPPORTED_ABI_VERSION: u8 = 2;
 const ABI_2_3: u8 = 0x32;
 use ton_block::Serializable;
 
+// Control flags byte of src address metadata. DeBot sets it with `flags` option of
+// external call (`tvm.buildExtMsg` or `extMsg: true` call options).
+/// Use `time` header value given by DeBot instead of current time.
+const FLAG_OVERRIDE_TS: u8 = 1;
+/// Use `expire` header value given by DeBot instead of default expiration.
+const FLAG_OVERRIDE_EXP: u8 = 2;
+/// Don't wait for transaction of on-chain call.
+const FLAG_ASYNC_CALL: u8 = 4;
+
 pub(super) enum DebotCallType {
     Interface { msg: String, id: String },
     GetMethod { msg: String, dest: String },
@@ -12,12 +22,21 @@ pub(super) enum DebotCallType {
     Invoke { msg: String },
 }
 
+/// Kind of external inbound message produced by DeBot.
+#[derive(Clone, Debug, PartialEq)]
+pub(super) enum ExtCallKind {
+    /// Get-method call, runs locally on the target account state.
+    GetMethod,
+    /// On-chain call, message is signed and sent to blockchain.
+    External,
+}
+
 fn msg_err(e: impl Display) -> ClientError {
     Error::invalid_msg(e)
 }
 
 #[derive(Default)]
-struct Metadata {
+pub(super) struct Metadata {
     answer_id: u32,
     onerror_id: u32,
     abi_ver: u8,
@@ -56,9 +75,9 @@ impl TryFrom<MsgAddressExt> for Metadata {
                     false => None,
                 };
                 let ctrl_flags = slice.get_next_byte().unwrap_or_default();
-                let override_ts = (ctrl_flags & 1) != 0;
-                let override_exp = (ctrl_flags & 2) != 0;
-                let async_call = (ctrl_flags & 4) != 0;
+                let override_ts = (ctrl_flags & FLAG_OVERRIDE_TS) != 0;
+                let override_exp = (ctrl_flags & FLAG_OVERRIDE_EXP) != 0;
+                let async_call = (ctrl_flags & FLAG_ASYNC_CALL) != 0;
 
                 Ok(Self {
                     answer_id,
@@ -77,6 +96,31 @@ impl TryFrom<MsgAddressExt> for Metadata {
     }
 }
 
+impl Metadata {
+    /// Reads metadata from src address of external inbound message.
+    pub fn from_message(msg: &Message) -> ClientResult<Self> {
+        let hdr = msg
+            .ext_in_header()
+            .ok_or_else(|| msg_err("not an external inbound message"))?;
+        Self::try_from(hdr.src.clone())
+    }
+
+    /// Detects if message is a get-method call or an on-chain call.
+    ///
+    /// Metadata emitted by DeBots has no field for call kind, so the signature bit
+    /// of the body decides: signed message is an on-chain call, unsigned one is a
+    /// get-method. Signed get-methods are still treated as on-chain calls, this stays
+    /// unresolved until DeBots declare call kind in metadata.
+    pub fn call_kind(&self, body: Option<SliceData>) -> ClientResult<ExtCallKind> {
+        let sign_bit = body_sign_bit(body)?;
+        Ok(if sign_bit {
+            ExtCallKind::External
+        } else {
+            ExtCallKind::GetMethod
+        })
+    }
+}
+
 #[cfg(not(feature = "wasm-base"))]
 pub fn prepare_ext_in_message(
     msg: &Message,
@@ -122,10 +166,10 @@ async fn decode_and_fix_ext_msg(
     let mut in_body_slice = message.body().ok_or_else(|| msg_err("empty body"))?;
     // skip signature bit and signature if present
     let sign_bit = in_body_slice.get_next_bit().map_err(msg_err)?;
-    if let Signer::SigningBox { handle: _ } = signer {
-        if sign_bit {
-            in_body_slice.get_next_bits(512).map_err(msg_err)?;
-        } else if !allow_no_signature {
+    if sign_bit {
+        in_body_slice.get_next_bits(512).map_err(msg_err)?;
+    } else if let Signer::SigningBox { handle: _ } = signer {
+        if !allow_no_signature {
             return Err(msg_err("signature bit is zero"));
         }
     }
@@ -251,16 +295,21 @@ impl ContractCall {
         target_state: String,
         debot_addr: String,
         local_run: bool,
+        boxes: Arc<BoxRegistry>,
     ) -> ClientResult<Self> {
         let mut msg: Message = deserialize_object_from_base64(&msg, "message")
             .map_err(msg_err)?
             .object;
         let meta = get_meta(&mut msg)?;
+        // signature is defined by DeBot's `sign` option, not by call kind:
+        // get-method can be signed if target contract checks `msg.pubkey()`.
+        let sign = body_sign_bit(msg.body())?;
         let signer = resolve_signer(
-            !local_run,
+            sign,
             signer,
             meta.signing_box_handle.clone(),
             browser.clone(),
+            boxes,
         )
         .await?;
         let dest_addr = msg
@@ -518,6 +567,7 @@ async fn resolve_signer(
     signer: Signer,
     msg_signing_box: Option<SigningBoxHandle>,
     browser: BrowserRef,
+    boxes: Arc<BoxRegistry>,
 ) -> ClientResult<Signer> {
     let new_signer = if sign {
         match signer {
@@ -525,10 +575,14 @@ async fn resolve_signer(
             _ => Signer::SigningBox {
                 handle: match msg_signing_box {
                     Some(signing_box_handle) => signing_box_handle,
-                    None => browser
-                        .get_signing_box()
-                        .await
-                        .map_err(Error::external_call_failed)?,
+                    None => {
+                        let handle = browser
+                            .get_signing_box()
+                            .await
+                            .map_err(Error::external_call_failed)?;
+                        boxes.add_signing_box(&handle);
+                        handle
+                    }
                 },
             },
         }
@@ -538,6 +592,12 @@ async fn resolve_signer(
     Ok(new_signer)
 }
 
+fn body_sign_bit(body: Option<SliceData>) -> ClientResult<bool> {
+    body.ok_or_else(|| msg_err("empty body"))?
+        .get_next_bit()
+        .map_err(msg_err)
+}
+
 fn get_meta(message: &mut Message) -> ClientResult<Metadata> {
     let src = std::mem::replace(
         &mut message
@@ -549,19 +609,28 @@ fn get_meta(message: &mut Message) -> ClientResult<Metadata> {
     Metadata::try_from(src)
 }
 
-async fn emulate_transaction(
+/// Result of local execution of a message on account state.
+pub(crate) struct Emulation {
+    /// Internal messages sent from account as a result of transaction.
+    pub out: Vec<Spending>,
+    /// BOCs of all outbound messages.
+    pub out_messages: Vec<String>,
+    /// Transaction total fee.
+    pub fee: u64,
+}
+
+/// Executes message on account state locally and collects its spendings.
+pub(crate) async fn emulate_message(
     client: TonClient,
-    dst: String,
     msg: String,
-    target_state: String,
-    signer: Signer,
-) -> ClientResult<DebotActivity> {
+    state: String,
+) -> ClientResult<Emulation> {
     let result = run_executor(
         client.clone(),
         ParamsOfRunExecutor {
-            message: msg.clone(),
+            message: msg,
             account: AccountForExecutor::Account {
-                boc: target_state,
+                boc: state,
                 unlimited_balance: None,
             },
             ..Default::default()
@@ -585,8 +654,14 @@ async fn emulate_transaction(
     }
 
     let mut out = vec![];
-    for out_msg in result.out_messages {
-        let parsed = parse_message(client.clone(), ParamsOfParse { boc: out_msg })?.parsed;
+    for out_msg in &result.out_messages {
+        let parsed = parse_message(
+            client.clone(),
+            ParamsOfParse {
+                boc: out_msg.clone(),
+            },
+        )?
+        .parsed;
         let msg_type = parsed["msg_type"].as_u64().unwrap();
         // if internal message
         if msg_type == 0 {
@@ -599,24 +674,103 @@ async fn emulate_transaction(
         }
     }
 
-    let (signing_box_handle, signkey) = if let Signer::SigningBox { ref handle } = signer {
-        (
+    Ok(Emulation {
+        out,
+        out_messages: result.out_messages,
+        fee: result.fees.total_account_fees,
+    })
+}
+
+/// Returns signing box handle and its public key.
+pub(crate) async fn signer_info(client: TonClient, signer: &Signer) -> ClientResult<(u32, String)> {
+    if let Signer::SigningBox { ref handle } = signer {
+        Ok((
             handle.0,
-            signer
-                .resolve_public_key(client.clone())
-                .await?
-                .unwrap_or_default(),
-        )
+            signer.resolve_public_key(client).await?.unwrap_or_default(),
+        ))
     } else {
-        (0, String::new())
-    };
+        Ok((0, String::new()))
+    }
+}
+
+async fn emulate_transaction(
+    client: TonClient,
+    dst: String,
+    msg: String,
+    target_state: String,
+    signer: Signer,
+) -> ClientResult<DebotActivity> {
+    let result = emulate_message(client.clone(), msg.clone(), target_state).await?;
+    let (signing_box_handle, signkey) = signer_info(client, &signer).await?;
     Ok(DebotActivity::Transaction {
-        msg: msg.clone(),
-        dst: dst.clone(),
-        out,
-        fee: result.fees.total_account_fees,
+        msg,
+        dst,
+        out: result.out,
+        fee: result.fee,
         setcode: false,
         signkey,
         signing_box_handle,
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ext_in_message(flags: u8, signed: bool) -> Message {
+        let mut src = BuilderData::new();
+        src.append_u32(1)
+            .and_then(|b| b.append_u32(2))
+            .and_then(|b| b.append_u8(ABI_2_3))
+            // no time, expire, pubkey headers and signing box handle
+            .and_then(|b| b.append_bits(0, 4))
+            .and_then(|b| b.append_u8(flags))
+            .unwrap();
+        let src = MsgAddressExt::with_extern(slice_from_cell(src.into_cell().unwrap()).unwrap())
+            .unwrap();
+        let mut body = BuilderData::new();
+        if signed {
+            body.append_bit_one().unwrap();
+            body.append_raw(&[0u8; 64], 512).unwrap();
+        } else {
+            body.append_bit_zero().unwrap();
+        }
+        body.append_u32(0x1234).unwrap();
+        let hdr = ton_block::ExternalInboundMessageHeader {
+            src,
+            dst: MsgAddressInt::default(),
+            import_fee: Default::default(),
+        };
+        let body = slice_from_cell(body.into_cell().unwrap()).unwrap();
+        Message::with_ext_in_header_and_body(hdr, body)
+    }
+
+    fn call_kind(msg: &Message) -> ExtCallKind {
+        Metadata::from_message(msg)
+            .unwrap()
+            .call_kind(msg.body())
+            .unwrap()
+    }
+
+    #[test]
+    fn test_call_kind() {
+        assert_eq!(call_kind(&ext_in_message(0, true)), ExtCallKind::External);
+        assert_eq!(call_kind(&ext_in_message(0, false)), ExtCallKind::GetMethod);
+        let msg = ext_in_message(FLAG_ASYNC_CALL, true);
+        assert_eq!(call_kind(&msg), ExtCallKind::External);
+        assert!(Metadata::from_message(&msg).unwrap().async_call);
+    }
+
+    #[test]
+    fn test_malformed_metadata() {
+        let src = MsgAddressExt::with_extern(SliceData::new(vec![0x12, 0x80])).unwrap();
+        assert!(Metadata::try_from(src).is_err());
+        assert!(Metadata::try_from(MsgAddressExt::AddrNone).is_err());
+        let mut msg = ext_in_message(0, false);
+        msg.set_body(SliceData::default());
+        assert!(Metadata::from_message(&msg)
+            .unwrap()
+            .call_kind(msg.body())
+            .is_err());
+    }
+}
@@ -1,14 +1,20 @@
 use crate::action::{AcType, DAction};
+use crate::boxes::{BoxRegistry, LiveBoxes};
 use crate::common::*;
 use crate::context::{DContext, STATE_CURRENT, STATE_EXIT, STATE_PREV, STATE_ZERO};
 use crate::builtin_interfaces::*;
+use crate::keys::KeyProviderRef;
+use crate::network_policy::NetworkPolicy;
+use crate::storage::StorageProviderRef;
 use crate::routines;
+use crate::subscriptions::{CollectionEvent, DebotEvent, EventReceiver, Subscriptions};
 use crate::sdk_prelude::*;
+use crate::wallet::WalletConfig;
 use std::collections::VecDeque;
 use ton_abi::Contract;
 use std::future::Future;
 
-const EMPTY_CELL: &str = "te6ccgEBAQEAAgAAAA==";
+pub(crate) const EMPTY_CELL: &str = "te6ccgEBAQEAAgAAAA==";
 
 fn create_client(endpoints: Option<Vec<String>>) -> Result<TonClient, String> {
     let cli_conf = ClientConfig {
@@ -29,9 +35,13 @@ fn load_abi(abi: &str) -> Result<Abi, String> {
     ))
 }
 
-// TODO: implement address validation
+/// Parses standard address in raw or user-friendly form and returns it in raw form.
 pub fn load_ton_address(addr: &str) -> Result<String, String> {
-    Ok(addr.to_owned())
+    match account_decode(addr.trim()) {
+        Ok(address @ MsgAddressInt::AddrStd(_)) => Ok(address.to_string()),
+        Ok(_) => Err(format!("invalid address \"{addr}\": not a standard address")),
+        Err(e) => Err(format!("invalid address \"{addr}\": {e}")),
+    }
 }
 
 //const OPTION_ABI: u8 = 1;
@@ -56,6 +66,9 @@ pub struct DEngine {
     browser: BrowserRef,
     builtin_interfaces: BuiltinInterfaces,
     info: DInfo,
+    wallet: Option<WalletConfig>,
+    boxes: Arc<BoxRegistry>,
+    subscriptions: Arc<Subscriptions>,
 }
 
 impl DEngine {
@@ -77,6 +90,14 @@ impl DEngine {
         let abi = abi
             .map_or_else(|| load_abi(DEBOT_ABI), |s| load_abi(&s))
             .unwrap();
+        let subscriptions = Arc::new(Subscriptions::new());
+        let mut builtin_interfaces = BuiltinInterfaces::new(ton.clone(), browser.clone());
+        builtin_interfaces.add(Arc::new(QueryV2Interface::new(
+            ton.clone(),
+            browser.clone(),
+            subscriptions.clone(),
+        )));
+        builtin_interfaces.add(Arc::new(TimerInterface::new(subscriptions.clone())));
         DEngine {
             raw_abi: String::new(),
             abi,
@@ -89,11 +110,142 @@ impl DEngine {
             target_addr: None,
             target_abi: None,
             browser: browser.clone(),
-            builtin_interfaces: BuiltinInterfaces::new(ton, browser),
+            builtin_interfaces,
             info: Default::default(),
+            wallet: None,
+            boxes: Arc::new(BoxRegistry::new(ton, browser)),
+            subscriptions,
         }
     }
 
+    /// Sets user wallet used to send internal messages on behalf of DeBot.
+    /// Must be called before `init`.
+    pub fn set_wallet(&mut self, wallet: Option<WalletConfig>) {
+        self.wallet = wallet;
+    }
+
+    /// Sets provider of user keys. Engine uses it to handle SigningBoxInput
+    /// and EncryptionBoxInput interfaces itself.
+    pub fn set_key_provider(&mut self, keys: KeyProviderRef) {
+        self.builtin_interfaces.add(Arc::new(SigningBoxInputInterface::new(
+            keys.clone(),
+            self.browser.clone(),
+            self.boxes.clone(),
+        )));
+        self.builtin_interfaces.add(Arc::new(EncryptionBoxInputInterface::new(
+            keys,
+            self.browser.clone(),
+            self.boxes.clone(),
+        )));
+    }
+
+    /// Sets user identity returned to DeBots by UserInfo interface.
+    pub fn set_user_profile(&mut self, profile: UserProfile) {
+        self.builtin_interfaces.add(Arc::new(UserInfoInterface::new(
+            profile,
+            self.browser.clone(),
+            self.boxes.clone(),
+        )));
+    }
+
+    /// Sets restrictions on network requests made by DeBot through Network interfaces.
+    pub fn set_network_policy(&mut self, policy: NetworkPolicy) {
+        let policy = Arc::new(policy);
+        self.builtin_interfaces.add(Arc::new(NetworkInterface::new(
+            self.browser.clone(),
+            policy.clone(),
+        )));
+        self.builtin_interfaces
+            .add(Arc::new(NetworkV2Interface::new(self.browser.clone(), policy)));
+    }
+
+    /// Sets storage used by Storage interface. DeBot data is limited by `quota` bytes.
+    pub fn set_storage(&mut self, storage: StorageProviderRef, quota: u32) {
+        self.builtin_interfaces.add(Arc::new(StorageInterface::new(
+            storage,
+            &self.addr,
+            quota,
+            self.browser.clone(),
+        )));
+    }
+
+    /// Returns SDK client used by engine. Boxes passed to engine must belong to it.
+    pub fn client(&self) -> TonClient {
+        self.ton.clone()
+    }
+
+    /// Returns signing and encryption boxes currently owned by engine.
+    pub fn live_boxes(&self) -> LiveBoxes {
+        self.boxes.live_boxes()
+    }
+
+    /// Removes all signing and encryption boxes owned by engine from SDK context.
+    pub fn release_boxes(&self) {
+        self.boxes.release_all();
+    }
+
+    /// Returns stream of collection updates of DeBot subscriptions and delayed
+    /// answers of Timer interface. Each event must be passed to `handle_event`.
+    /// Timer interface fails DeBot requests until this stream is taken.
+    pub fn events(&self) -> EventReceiver {
+        self.subscriptions.events()
+    }
+
+    pub(crate) fn subscriptions(&self) -> Arc<Subscriptions> {
+        self.subscriptions.clone()
+    }
+
+    /// Runs DeBot subscription callback with collection update or sends
+    /// delayed interface answer to DeBot. Updates of cancelled subscriptions are ignored.
+    pub async fn handle_event(&mut self, event: DebotEvent) -> ClientResult<()> {
+        match event {
+            DebotEvent::Collection(event) => self.handle_collection_event(event).await,
+            DebotEvent::Answer {
+                source,
+                answer_id,
+                params,
+            } => {
+                let result = async {
+                    let output = self.run_debot_internal(source, answer_id, params).await?;
+                    self.handle_output(output).await
+                }
+                .await;
+                if let Err(e) = &result {
+                    error!(self.browser, "delayed answer failed: {}", e);
+                }
+                result
+            }
+        }
+    }
+
+    async fn handle_collection_event(&mut self, event: CollectionEvent) -> ClientResult<()> {
+        let callback_id = match self.subscriptions.callback(event.subscription_id) {
+            Some(id) => id,
+            None => return Ok(()),
+        };
+        let result = async {
+            let (source, args) = encode_event(event).map_err(Error::execute_failed)?;
+            let output = self.run_debot_internal(source, callback_id, args).await?;
+            self.handle_output(output).await
+        }
+        .await;
+        if let Err(e) = &result {
+            error!(self.browser, "subscription callback failed: {}", e);
+        }
+        result
+    }
+
+    /// Cancels all DeBot subscriptions. Engine doesn't accept updates after that.
+    pub async fn unsubscribe_all(&self) {
+        for id in self.subscriptions.ids() {
+            self.subscriptions.remove(id);
+            if let Err(e) = self.browser.unsubscribe_collection(id).await {
+                error!(self.browser, "failed to cancel subscription {}: {}", id, e);
+            }
+        }
+        self.subscriptions.close();
+    }
+
     pub async fn fetch(client: TonClient, addr: String) -> Result<DInfo, String> {
         let state = Self::fetch_state_with_client(client.clone(), addr.clone()).await?;
         Self::fetch_info_from_state(client, addr, state).await
@@ -167,6 +319,8 @@ impl DEngine {
                 self.addr.clone(),
                 self.abi.clone(),
                 self.browser.clone(),
+                self.wallet.clone(),
+                self.boxes.clone(),
             )));
             self.builtin_interfaces
                 .add(Arc::new(JsonInterface::new(&self.raw_abi)));
@@ -274,7 +428,9 @@ impl DEngine {
             AcType::SendMsg => {
                 debug!(self.browser, "sendmsg: {}", a.name);
                 let signer = if a.sign_by_user() {
-                    Some(self.browser.get_signing_box().await?)
+                    let signing_box = self.browser.get_signing_box().await?;
+                    self.boxes.add_signing_box(&signing_box);
+                    Some(signing_box)
                 } else {
                     None
                 };
@@ -283,15 +439,11 @@ impl DEngine {
                 } else {
                     None
                 };
-                let result = self.run_sendmsg(&a.name, args, signer.clone()).await?;
+                let result = self.run_sendmsg(&a.name, args, signer.clone()).await;
                 if let Some(signing_box) = signer {
-                    let _ = remove_signing_box(
-                        self.ton.clone(),
-                        RegisteredSigningBox {
-                            handle: signing_box,
-                        },
-                    );
+                    self.boxes.release_signing_box(&signing_box);
                 }
+                let result = result?;
                 self.browser.log(LogLevel::User, "Transaction succeeded.".to_string());
                 result.map(|r| self.browser.log(LogLevel::User, format!("Result: {r}")));
                 Ok(None)
@@ -474,7 +626,7 @@ impl DEngine {
         let body = result["body"].as_str().unwrap();
         let state = result["state"].as_str();
 
-        let call_itself = load_ton_address(dest)? == self.addr;
+        let call_itself = load_ton_address(dest)? == load_ton_address(&self.addr)?;
         let abi = if call_itself {
             self.abi.clone()
         } else {
@@ -524,7 +676,13 @@ impl DEngine {
     }
 
     pub(crate) async fn fetch_state(&self, addr: String) -> Result<String, String> {
-        let b = self.browser.clone();
+        Self::fetch_state_with_browser(self.browser.clone(), addr).await
+    }
+
+    pub(crate) async fn fetch_state_with_browser(
+        b: BrowserRef,
+        addr: String,
+    ) -> Result<String, String> {
         let closure = |addr: String| {
             async move {
                 b.query_collection(
@@ -761,6 +919,10 @@ impl DEngine {
                         None => self.browser.send(msg).await,
                         Some(result) => {
                             let (fname, args) = result.map_err(Error::execute_failed)?;
+                            if args.is_null() {
+                                // Answer is delivered later through `handle_event`.
+                                continue;
+                            }
                             let new_outputs = self
                                 .run_debot_internal(format!("{DEBOT_WC}:{id}"), fname, args)
                                 .await?;
@@ -781,6 +943,7 @@ impl DEngine {
                         target_state,
                         self.addr.clone(),
                         true,
+                        self.boxes.clone(),
                     )
                     .await?;
                     let answer_msg = callobj.execute(true).await?;
@@ -799,6 +962,7 @@ impl DEngine {
                         target_state,
                         self.addr.clone(),
                         false,
+                        self.boxes.clone(),
                     )
                     .await?;
                     let answer_msg = callobj.execute(true).await?;
@@ -30,6 +30,9 @@ pub enum ErrorCode {
     DebotBrowserCallbackFailed = 811,
     DebotOperationRejected = 812,
     DebotNoCode = 813,
+    DebotWalletNotConfigured = 814,
+    DebotKeyNotFound = 815,
+    DebotStorageFailed = 816,
 }
 pub struct Error;
 
@@ -123,4 +126,25 @@ impl Error {
     pub fn debot_has_no_code() -> ClientError {
         error(ErrorCode::DebotNoCode, "Debot has no code".to_string())
     }
+
+    pub fn wallet_not_configured() -> ClientError {
+        error(
+            ErrorCode::DebotWalletNotConfigured,
+            "User wallet is not configured".to_string(),
+        )
+    }
+
+    pub fn key_not_found(public: impl Display) -> ClientError {
+        error(
+            ErrorCode::DebotKeyNotFound,
+            format!("Key with public key {public} not found"),
+        )
+    }
+
+    pub fn storage_failed(err: impl Display) -> ClientError {
+        error(
+            ErrorCode::DebotStorageFailed,
+            format!("Debot storage failed: {err}"),
+        )
+    }
 }
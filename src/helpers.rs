@@ -21,3 +21,22 @@ pub(crate) fn build_internal_message(
 pub(crate) fn now_ms() -> u64 {
     chrono::prelude::Utc::now().timestamp_millis() as u64
 }
+
+/// Runs future in background on current async runtime.
+/// Returns false if there is no runtime to run it.
+#[cfg(not(feature = "wasm"))]
+pub(crate) fn spawn(future: impl std::future::Future<Output = ()> + Send + 'static) -> bool {
+    match tokio::runtime::Handle::try_current() {
+        Ok(handle) => {
+            handle.spawn(future);
+            true
+        }
+        Err(_) => false,
+    }
+}
+
+#[cfg(feature = "wasm")]
+pub(crate) fn spawn(future: impl std::future::Future<Output = ()> + Send + 'static) -> bool {
+    wasm_bindgen_futures::spawn_local(future);
+    true
+}
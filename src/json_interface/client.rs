@@ -14,10 +14,12 @@
 use lockfree::map::Map as LockfreeMap;
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 use std::collections::HashMap;
+use std::path::PathBuf;
 use std::sync::atomic::{AtomicU32, Ordering};
 use std::sync::Arc;
 use tokio::sync::{oneshot, Mutex};
-use crate::prelude::DEngine;
+use crate::prelude::{DEngine, FileStorageProvider};
+use crate::subscriptions::Subscriptions;
 use ton_client::error::ClientResult;
 use ton_client::client::Error;
 use super::{interop::ResponseType, request::Request};
@@ -82,6 +84,10 @@ pub struct DengineContext {
 
     // debot module
     pub(crate) debots: LockfreeMap<u32, Mutex<DEngine>>,
+    // Subscriptions of debots, available without locking engine.
+    pub(crate) subscriptions: LockfreeMap<u32, Arc<Subscriptions>>,
+    // File storages shared by debots, keyed by absolute path.
+    file_storages: std::sync::Mutex<HashMap<PathBuf, Arc<FileStorageProvider>>>,
 }
 
 impl std::fmt::Debug for DengineContext {
@@ -98,6 +104,8 @@ impl DengineContext {
             access_key,
             async_runtime_handle: get_current_runtime_handle()?,
             debots: LockfreeMap::new(),
+            subscriptions: LockfreeMap::new(),
+            file_storages: std::sync::Mutex::new(HashMap::new()),
             app_requests: Mutex::new(HashMap::new()),
             next_id: AtomicU32::new(1),
             binding: Default::default(),
@@ -122,6 +130,19 @@ impl DengineContext {
         wasm_bindgen_futures::spawn_local(future);
     }
 
+    /// Returns provider of the storage file. DeBots using the same file share
+    /// one provider, so their changes don't overwrite each other.
+    pub(crate) fn file_storage(&self, path: &str) -> Arc<FileStorageProvider> {
+        let path = std::env::current_dir()
+            .map_or_else(|_| PathBuf::from(path), |dir| dir.join(path));
+        self.file_storages
+            .lock()
+            .unwrap()
+            .entry(path.clone())
+            .or_insert_with(|| Arc::new(FileStorageProvider::new(path)))
+            .clone()
+    }
+
     pub(crate) fn get_next_id(&self) -> u32 {
         self.next_id.fetch_add(1, Ordering::Relaxed)
     }
@@ -162,6 +183,16 @@ pub(crate) struct AppObject<P: Serialize, R: DeserializeOwned> {
     phantom: std::marker::PhantomData<(P, R)>,
 }
 
+impl<P: Serialize, R: DeserializeOwned> Clone for AppObject<P, R> {
+    fn clone(&self) -> Self {
+        Self {
+            context: self.context.clone(),
+            object_handler: self.object_handler.clone(),
+            phantom: std::marker::PhantomData,
+        }
+    }
+}
+
 impl<P, R> AppObject<P, R>
 where
     P: Serialize,
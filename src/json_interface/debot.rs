@@ -15,10 +15,12 @@
 use super::client::{AppObject, DengineContext};
 use crate::bridge_api::{ParamsOfInit, RegisteredDebot};
 use crate::prelude::{
-    BrowserCallbacks, DAction, DebotActivity, Error, FetchHeader, FetchResponse,
-    WaitForTransactionParams, LogLevel,
+    BrowserCallbacks, DAction, DateTimeKind, DebotActivity, Error, EventSink, FetchHeader,
+    FetchRequest, FetchResponse, KeyProvider, KeyProviderRef, LogLevel, MenuItem, StorageProvider,
+    StorageProviderRef, WaitForTransactionParams,
 };
 use crate::sdk_prelude::*;
+use ton_client::crypto::EncryptionAlgorithm;
 use api_derive::{api_function, ApiType};
 use serde_derive::{Deserialize, Serialize};
 
@@ -61,6 +63,9 @@ pub enum ResultOfAppDebotBrowser {
     WaitForCollection {
         result: ResultOfWaitForCollection,
     },
+    AggregateCollection {
+        result: ResultOfAggregateCollection,
+    },
     WaitForTransaction {
         result: ResultOfProcessMessage,
     },
@@ -73,6 +78,104 @@ pub enum ResultOfAppDebotBrowser {
     GetEncryptionBoxInfo {
         result: EncryptionBoxInfo,
     },
+    /// Result of `input_text` callback.
+    InputText {
+        /// Text entered by user.
+        value: String,
+    },
+    /// Result of `select_menu` callback.
+    SelectMenu {
+        /// Index of the menu item selected by user.
+        index: u32,
+    },
+    /// Result of `input_address` callback.
+    InputAddress {
+        /// Address entered by user.
+        address: String,
+    },
+    /// Result of `select_wallet` callback.
+    SelectWallet {
+        /// Address of the wallet selected by user.
+        address: String,
+    },
+    /// Result of `input_amount` callback.
+    InputAmount {
+        /// Decimal amount entered by user.
+        amount: String,
+    },
+    /// Result of `confirm` callback.
+    Confirm {
+        /// User answer.
+        confirmed: bool,
+    },
+    /// Result of `input_number` callback.
+    InputNumber {
+        /// Number entered by user.
+        number: String,
+    },
+    /// Result of `input_datetime` callback.
+    InputDatetime {
+        /// Date and/or time entered by user.
+        value: String,
+    },
+    /// Result of `get_time_zone_offset` callback.
+    GetTimeZoneOffset {
+        /// Time zone offset in minutes east of UTC.
+        offset: i16,
+    },
+    /// Result of `select_key` callback.
+    SelectKey {
+        /// Public key selected by user.
+        public_key: String,
+    },
+    /// Result of `scan_qr_code` callback.
+    ScanQrCode {
+        /// QR code content.
+        value: String,
+    },
+    /// Result of `output_media` callback.
+    OutputMedia,
+    /// Result of `fetch_binary` callback.
+    FetchBinary {
+        /// Response with content encoded as base64.
+        response: FetchResponse,
+    },
+    /// Result of `subscribe_collection` callback.
+    SubscribeCollection,
+    /// Result of `unsubscribe_collection` callback.
+    UnsubscribeCollection,
+    /// Result of storage `get` request.
+    StorageGet {
+        /// Value of the key or `None` if key is not found.
+        value: Option<String>,
+    },
+    /// Result of storage `set` request.
+    StorageSet {
+        /// False if value is not set because quota is exceeded.
+        stored: bool,
+    },
+    /// Result of storage `remove` request.
+    StorageRemove,
+    /// Result of storage `keys` request.
+    StorageKeys {
+        /// All keys of namespace.
+        keys: Vec<String>,
+    },
+    /// Result of `keys_list` request.
+    KeysList {
+        /// Public keys of all keys available to user.
+        public_keys: Vec<String>,
+    },
+    /// Result of `keys_get_signing_box` request.
+    KeysGetSigningBox {
+        /// Signing box owned and disposed by debot engine.
+        signing_box: SigningBoxHandle,
+    },
+    /// Result of `keys_get_encryption_box` request.
+    KeysGetEncryptionBox {
+        /// Encryption box owned and disposed by debot engine.
+        encryption_box: EncryptionBoxHandle,
+    },
 }
 
 ///  [DEPRECATED](DEPRECATED.md) Debot Browser callbacks
@@ -130,6 +233,9 @@ pub enum ParamsOfAppDebotBrowser {
     WaitForCollection {
         params: ParamsOfWaitForCollection,
     },
+    AggregateCollection {
+        params: ParamsOfAggregateCollection,
+    },
     WaitForTransaction {
         params: WaitForTransactionParams,
     },
@@ -142,6 +248,150 @@ pub enum ParamsOfAppDebotBrowser {
     GetEncryptionBoxInfo {
         handle: EncryptionBoxHandle,
     },
+    /// Print DeBot message to user.
+    Print {
+        /// A string that must be printed to user.
+        message: String,
+    },
+    /// Request text from user.
+    InputText {
+        /// A string that must be printed to user before input.
+        prompt: String,
+        /// User can enter several lines of text.
+        multiline: bool,
+    },
+    /// Show menu to user.
+    SelectMenu {
+        /// Menu title.
+        title: String,
+        /// Menu description.
+        description: String,
+        /// Menu items.
+        items: Vec<MenuItem>,
+    },
+    /// Request address from user.
+    InputAddress {
+        /// A string that must be printed to user before input.
+        prompt: String,
+    },
+    /// Request user to select one of the user's wallets.
+    SelectWallet,
+    /// Request amount from user.
+    InputAmount {
+        /// A string that must be printed to user before input.
+        prompt: String,
+        /// Number of digits after point.
+        decimals: u8,
+        /// Minimal amount in minimal units.
+        min: String,
+        /// Maximal amount in minimal units.
+        max: String,
+    },
+    /// Request confirmation from user.
+    Confirm {
+        /// A string that must be printed to user before input.
+        prompt: String,
+    },
+    /// Request integer number from user.
+    InputNumber {
+        /// A string that must be printed to user before input.
+        prompt: String,
+        /// Minimal number.
+        min: String,
+        /// Maximal number.
+        max: String,
+    },
+    /// Request date and/or time from user.
+    InputDatetime {
+        /// A string that must be printed to user before input.
+        prompt: String,
+        /// Requested value format.
+        kind: DateTimeKind,
+        /// Default value.
+        default: String,
+        /// Minimal value.
+        min: String,
+        /// Maximal value.
+        max: String,
+    },
+    /// Get user time zone offset.
+    GetTimeZoneOffset,
+    /// Request user to choose one of the keys.
+    SelectKey {
+        /// A string that must be printed to user before input.
+        prompt: String,
+        /// Public keys to choose from.
+        public_keys: Vec<String>,
+    },
+    /// Scan QR code with camera.
+    ScanQrCode {
+        /// A string that must be printed to user before scanning.
+        prompt: String,
+    },
+    /// Show media content to user.
+    OutputMedia {
+        /// A string that must be printed to user with media.
+        prompt: String,
+        /// Data URL with base64 payload.
+        data: String,
+    },
+    /// Http(s) request with binary body.
+    FetchBinary {
+        request: FetchRequest,
+    },
+    /// Subscribe to collection updates. Updates must be passed to engine
+    /// with `send_collection_event` function.
+    SubscribeCollection {
+        /// Subscription ID assigned by engine.
+        subscription_id: u32,
+        params: ParamsOfSubscribeCollection,
+    },
+    /// Cancel subscription to collection updates.
+    UnsubscribeCollection {
+        /// Subscription ID assigned by engine.
+        subscription_id: u32,
+    },
+    /// Get value from DeBot storage.
+    StorageGet {
+        /// DeBot address in raw form.
+        namespace: String,
+        key: String,
+    },
+    /// Set value in DeBot storage if total size of keys and values of namespace
+    /// with the new value doesn't exceed quota. Check and change must be atomic.
+    StorageSet {
+        /// DeBot address in raw form.
+        namespace: String,
+        key: String,
+        value: String,
+        /// Maximal size of DeBot data in bytes.
+        quota: u32,
+    },
+    /// Remove key from DeBot storage.
+    StorageRemove {
+        /// DeBot address in raw form.
+        namespace: String,
+        key: String,
+    },
+    /// Get all keys of DeBot storage.
+    StorageKeys {
+        /// DeBot address in raw form.
+        namespace: String,
+    },
+    /// Get public keys of all keys available to user.
+    KeysList,
+    /// Get signing box for the user key.
+    KeysGetSigningBox {
+        /// Public key selected by user.
+        public_key: String,
+    },
+    /// Get encryption box for the user key.
+    KeysGetEncryptionBox {
+        /// Public key selected by user.
+        public_key: String,
+        /// Encryption algorithm without key material. Application fills it with the user key.
+        algorithm: EncryptionAlgorithm,
+    },
 }
 
 /// Wrapper for native Debot Browser callbacks.
@@ -232,6 +482,18 @@ impl BrowserCallbacks for DebotBrowserAdapter {
         }
     }
 
+    async fn fetch_binary(&self, request: FetchRequest) -> ClientResult<FetchResponse> {
+        let response = self
+            .app_object
+            .call(ParamsOfAppDebotBrowser::FetchBinary { request })
+            .await?;
+
+        match response {
+            ResultOfAppDebotBrowser::FetchBinary { response } => Ok(response),
+            _ => Err(unexpected_response_err()),
+        }
+    }
+
     async fn encrypt(&self, handle: EncryptionBoxHandle, data: String) -> ClientResult<String> {
         let response = self
             .app_object
@@ -321,6 +583,20 @@ impl BrowserCallbacks for DebotBrowserAdapter {
         }
     }
 
+    async fn aggregate_collection(
+        &self,
+        params: ParamsOfAggregateCollection,
+    ) -> ClientResult<ResultOfAggregateCollection> {
+        let response = self
+            .app_object
+            .call(ParamsOfAppDebotBrowser::AggregateCollection { params })
+            .await?;
+        match response {
+            ResultOfAppDebotBrowser::AggregateCollection { result } => Ok(result),
+            _ => Err(unexpected_response_err()),
+        }
+    }
+
     async fn wait_for_transaction(
         &self,
         params: WaitForTransactionParams,
@@ -373,6 +649,345 @@ impl BrowserCallbacks for DebotBrowserAdapter {
             _ => Err(unexpected_response_err()),
         }
     }
+
+    async fn print(&self, message: String) -> ClientResult<()> {
+        self.app_object
+            .notify(ParamsOfAppDebotBrowser::Print { message });
+        Ok(())
+    }
+
+    async fn input_text(&self, prompt: String, multiline: bool) -> ClientResult<String> {
+        let response = self
+            .app_object
+            .call(ParamsOfAppDebotBrowser::InputText { prompt, multiline })
+            .await?;
+        match response {
+            ResultOfAppDebotBrowser::InputText { value } => Ok(value),
+            _ => Err(unexpected_response_err()),
+        }
+    }
+
+    async fn select_menu(
+        &self,
+        title: String,
+        description: String,
+        items: Vec<MenuItem>,
+    ) -> ClientResult<u32> {
+        let response = self
+            .app_object
+            .call(ParamsOfAppDebotBrowser::SelectMenu {
+                title,
+                description,
+                items,
+            })
+            .await?;
+        match response {
+            ResultOfAppDebotBrowser::SelectMenu { index } => Ok(index),
+            _ => Err(unexpected_response_err()),
+        }
+    }
+
+    async fn input_address(&self, prompt: String) -> ClientResult<String> {
+        let response = self
+            .app_object
+            .call(ParamsOfAppDebotBrowser::InputAddress { prompt })
+            .await?;
+        match response {
+            ResultOfAppDebotBrowser::InputAddress { address } => Ok(address),
+            _ => Err(unexpected_response_err()),
+        }
+    }
+
+    async fn select_wallet(&self) -> ClientResult<String> {
+        let response = self
+            .app_object
+            .call(ParamsOfAppDebotBrowser::SelectWallet)
+            .await?;
+        match response {
+            ResultOfAppDebotBrowser::SelectWallet { address } => Ok(address),
+            _ => Err(unexpected_response_err()),
+        }
+    }
+
+    async fn input_amount(
+        &self,
+        prompt: String,
+        decimals: u8,
+        min: String,
+        max: String,
+    ) -> ClientResult<String> {
+        let response = self
+            .app_object
+            .call(ParamsOfAppDebotBrowser::InputAmount {
+                prompt,
+                decimals,
+                min,
+                max,
+            })
+            .await?;
+        match response {
+            ResultOfAppDebotBrowser::InputAmount { amount } => Ok(amount),
+            _ => Err(unexpected_response_err()),
+        }
+    }
+
+    async fn confirm(&self, prompt: String) -> ClientResult<bool> {
+        let response = self
+            .app_object
+            .call(ParamsOfAppDebotBrowser::Confirm { prompt })
+            .await?;
+        match response {
+            ResultOfAppDebotBrowser::Confirm { confirmed } => Ok(confirmed),
+            _ => Err(unexpected_response_err()),
+        }
+    }
+
+    async fn input_number(
+        &self,
+        prompt: String,
+        min: String,
+        max: String,
+    ) -> ClientResult<String> {
+        let response = self
+            .app_object
+            .call(ParamsOfAppDebotBrowser::InputNumber { prompt, min, max })
+            .await?;
+        match response {
+            ResultOfAppDebotBrowser::InputNumber { number } => Ok(number),
+            _ => Err(unexpected_response_err()),
+        }
+    }
+
+    async fn input_datetime(
+        &self,
+        prompt: String,
+        kind: DateTimeKind,
+        default: String,
+        min: String,
+        max: String,
+    ) -> ClientResult<String> {
+        let response = self
+            .app_object
+            .call(ParamsOfAppDebotBrowser::InputDatetime {
+                prompt,
+                kind,
+                default,
+                min,
+                max,
+            })
+            .await?;
+        match response {
+            ResultOfAppDebotBrowser::InputDatetime { value } => Ok(value),
+            _ => Err(unexpected_response_err()),
+        }
+    }
+
+    async fn get_time_zone_offset(&self) -> ClientResult<i16> {
+        let response = self
+            .app_object
+            .call(ParamsOfAppDebotBrowser::GetTimeZoneOffset)
+            .await?;
+        match response {
+            ResultOfAppDebotBrowser::GetTimeZoneOffset { offset } => Ok(offset),
+            _ => Err(unexpected_response_err()),
+        }
+    }
+
+    async fn select_key(&self, prompt: String, public_keys: Vec<String>) -> ClientResult<String> {
+        let response = self
+            .app_object
+            .call(ParamsOfAppDebotBrowser::SelectKey {
+                prompt,
+                public_keys,
+            })
+            .await?;
+        match response {
+            ResultOfAppDebotBrowser::SelectKey { public_key } => Ok(public_key),
+            _ => Err(unexpected_response_err()),
+        }
+    }
+
+    async fn scan_qr_code(&self, prompt: String) -> ClientResult<String> {
+        let response = self
+            .app_object
+            .call(ParamsOfAppDebotBrowser::ScanQrCode { prompt })
+            .await?;
+        match response {
+            ResultOfAppDebotBrowser::ScanQrCode { value } => Ok(value),
+            _ => Err(unexpected_response_err()),
+        }
+    }
+
+    async fn output_media(&self, prompt: String, data: String) -> ClientResult<()> {
+        let response = self
+            .app_object
+            .call(ParamsOfAppDebotBrowser::OutputMedia { prompt, data })
+            .await?;
+        match response {
+            ResultOfAppDebotBrowser::OutputMedia => Ok(()),
+            _ => Err(unexpected_response_err()),
+        }
+    }
+
+    async fn subscribe_collection(
+        &self,
+        params: ParamsOfSubscribeCollection,
+        events: EventSink,
+    ) -> ClientResult<()> {
+        // Application delivers updates through `send_collection_event`.
+        let response = self
+            .app_object
+            .call(ParamsOfAppDebotBrowser::SubscribeCollection {
+                subscription_id: events.subscription_id(),
+                params,
+            })
+            .await?;
+        match response {
+            ResultOfAppDebotBrowser::SubscribeCollection => Ok(()),
+            _ => Err(unexpected_response_err()),
+        }
+    }
+
+    async fn unsubscribe_collection(&self, subscription_id: u32) -> ClientResult<()> {
+        let response = self
+            .app_object
+            .call(ParamsOfAppDebotBrowser::UnsubscribeCollection { subscription_id })
+            .await?;
+        match response {
+            ResultOfAppDebotBrowser::UnsubscribeCollection => Ok(()),
+            _ => Err(unexpected_response_err()),
+        }
+    }
+}
+
+/// Storage provider routing Storage interface requests to application.
+pub(crate) struct AppStorageProvider {
+    app_object: AppObject<ParamsOfAppDebotBrowser, ResultOfAppDebotBrowser>,
+}
+
+impl AppStorageProvider {
+    pub fn new(app_object: AppObject<ParamsOfAppDebotBrowser, ResultOfAppDebotBrowser>) -> Self {
+        Self { app_object }
+    }
+}
+
+#[async_trait::async_trait]
+impl StorageProvider for AppStorageProvider {
+    async fn get(&self, namespace: &str, key: &str) -> ClientResult<Option<String>> {
+        let response = self
+            .app_object
+            .call(ParamsOfAppDebotBrowser::StorageGet {
+                namespace: namespace.to_owned(),
+                key: key.to_owned(),
+            })
+            .await?;
+        match response {
+            ResultOfAppDebotBrowser::StorageGet { value } => Ok(value),
+            _ => Err(unexpected_response_err()),
+        }
+    }
+
+    async fn set_with_quota(
+        &self,
+        namespace: &str,
+        key: &str,
+        value: String,
+        quota: usize,
+    ) -> ClientResult<bool> {
+        let response = self
+            .app_object
+            .call(ParamsOfAppDebotBrowser::StorageSet {
+                namespace: namespace.to_owned(),
+                key: key.to_owned(),
+                value,
+                quota: quota as u32,
+            })
+            .await?;
+        match response {
+            ResultOfAppDebotBrowser::StorageSet { stored } => Ok(stored),
+            _ => Err(unexpected_response_err()),
+        }
+    }
+
+    async fn remove(&self, namespace: &str, key: &str) -> ClientResult<()> {
+        let response = self
+            .app_object
+            .call(ParamsOfAppDebotBrowser::StorageRemove {
+                namespace: namespace.to_owned(),
+                key: key.to_owned(),
+            })
+            .await?;
+        match response {
+            ResultOfAppDebotBrowser::StorageRemove => Ok(()),
+            _ => Err(unexpected_response_err()),
+        }
+    }
+
+    async fn keys(&self, namespace: &str) -> ClientResult<Vec<String>> {
+        let response = self
+            .app_object
+            .call(ParamsOfAppDebotBrowser::StorageKeys {
+                namespace: namespace.to_owned(),
+            })
+            .await?;
+        match response {
+            ResultOfAppDebotBrowser::StorageKeys { keys } => Ok(keys),
+            _ => Err(unexpected_response_err()),
+        }
+    }
+}
+
+/// Key provider routing SigningBoxInput and EncryptionBoxInput key requests to application.
+pub(crate) struct AppKeyProvider {
+    app_object: AppObject<ParamsOfAppDebotBrowser, ResultOfAppDebotBrowser>,
+}
+
+impl AppKeyProvider {
+    pub fn new(app_object: AppObject<ParamsOfAppDebotBrowser, ResultOfAppDebotBrowser>) -> Self {
+        Self { app_object }
+    }
+}
+
+#[async_trait::async_trait]
+impl KeyProvider for AppKeyProvider {
+    async fn list_keys(&self) -> ClientResult<Vec<String>> {
+        let response = self.app_object.call(ParamsOfAppDebotBrowser::KeysList).await?;
+        match response {
+            ResultOfAppDebotBrowser::KeysList { public_keys } => Ok(public_keys),
+            _ => Err(unexpected_response_err()),
+        }
+    }
+
+    async fn get_signing_box(&self, public: &str) -> ClientResult<SigningBoxHandle> {
+        let response = self
+            .app_object
+            .call(ParamsOfAppDebotBrowser::KeysGetSigningBox {
+                public_key: public.to_owned(),
+            })
+            .await?;
+        match response {
+            ResultOfAppDebotBrowser::KeysGetSigningBox { signing_box } => Ok(signing_box),
+            _ => Err(unexpected_response_err()),
+        }
+    }
+
+    async fn get_encryption_box(
+        &self,
+        public: &str,
+        algorithm: EncryptionAlgorithm,
+    ) -> ClientResult<EncryptionBoxHandle> {
+        let response = self
+            .app_object
+            .call(ParamsOfAppDebotBrowser::KeysGetEncryptionBox {
+                public_key: public.to_owned(),
+                algorithm,
+            })
+            .await?;
+        match response {
+            ResultOfAppDebotBrowser::KeysGetEncryptionBox { encryption_box } => Ok(encryption_box),
+            _ => Err(unexpected_response_err()),
+        }
+    }
 }
 
 /// Creates and instance of DeBot.
@@ -388,6 +1003,15 @@ pub(crate) async fn init(
     params: ParamsOfInit,
     app_object: AppObject<ParamsOfAppDebotBrowser, ResultOfAppDebotBrowser>,
 ) -> ClientResult<RegisteredDebot> {
+    let app_storage: StorageProviderRef = Arc::new(AppStorageProvider::new(app_object.clone()));
+    let app_keys: KeyProviderRef = Arc::new(AppKeyProvider::new(app_object.clone()));
     let browser_callbacks = DebotBrowserAdapter::new(app_object);
-    crate::bridge_api::init(context, params, browser_callbacks).await
+    crate::bridge_api::init_with_app_providers(
+        context,
+        params,
+        browser_callbacks,
+        Some(app_storage),
+        Some(app_keys),
+    )
+    .await
 }
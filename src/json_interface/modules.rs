@@ -14,7 +14,10 @@
 
 use super::registrar::ModuleReg;
 use super::runtime::RuntimeHandlers;
-use crate::bridge_api::{fetch_api, remove_api, send_api, start_api, DebotHandle};
+use crate::bridge_api::{
+    fetch_api, get_boxes_api, remove_api, send_api, send_collection_event_api, start_api,
+    DebotHandle, ParamsOfGetBoxes, ParamsOfSendCollectionEvent,
+};
 use crate::browser::{FetchHeader, FetchResponse};
 use crate::prelude::*;
 use api_derive::ApiModule;
@@ -104,6 +107,10 @@ fn register_debot(handlers: &mut RuntimeHandlers) {
         SortDirection,
         ParamsOfWaitForCollection,
         ResultOfWaitForCollection,
+        ParamsOfAggregateCollection,
+        ResultOfAggregateCollection,
+        FieldAggregation,
+        AggregationFn,
         EncryptionBoxInfo,
         MessageNode,
         TransactionNode,
@@ -117,13 +124,29 @@ fn register_debot(handlers: &mut RuntimeHandlers) {
         Abi, AbiContract, AbiEvent, AbiFunction, AbiParam, AbiData, AbiHandle,
         MessageBodyType,
         FunctionHeader,
-        LogLevel
+        LogLevel,
+        WalletConfig,
+        ParamsOfGetBoxes,
+        LiveBoxes,
+        MenuItem,
+        DateTimeKind,
+        UserProfile,
+        FetchRequest,
+        NetworkPolicy,
+        StorageConfig,
+        StorageBackend,
+        KeyProviderConfig,
+        ParamsOfSubscribeCollection,
+        CollectionEvent,
+        ParamsOfSendCollectionEvent
     );
     module.register_async_fn_with_app_object(super::debot::init, super::debot::init_api);
     module.register_async_fn(crate::start, start_api);
     module.register_async_fn(crate::fetch, fetch_api);
     module.register_async_fn(crate::send, send_api);
-    module.register_sync_fn(crate::remove, remove_api);
+    module.register_async_fn(crate::remove, remove_api);
+    module.register_async_fn(crate::get_boxes, get_boxes_api);
+    module.register_sync_fn(crate::send_collection_event, send_collection_event_api);
     module.register();
 }
 
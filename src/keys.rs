@@ -0,0 +1,103 @@
+use crate::common::Error;
+use crate::sdk_prelude::*;
+use api_derive::ApiType;
+use serde_derive::{Deserialize, Serialize};
+use ton_client::crypto::{
+    create_encryption_box, EncryptionAlgorithm, ParamsOfCreateEncryptionBox,
+};
+
+/// Source of user keys for SigningBoxInput and EncryptionBoxInput interfaces.
+///
+/// Keys are identified by public keys encoded as hex strings (64 characters).
+#[async_trait::async_trait]
+pub trait KeyProvider {
+    /// Returns public keys of all keys available to user.
+    async fn list_keys(&self) -> ClientResult<Vec<String>>;
+    /// Creates signing box for the key with the given public key.
+    async fn get_signing_box(&self, public: &str) -> ClientResult<SigningBoxHandle>;
+    /// Creates encryption box for the key with the given public key.
+    /// Key material of `algorithm` is filled by provider.
+    async fn get_encryption_box(
+        &self,
+        public: &str,
+        algorithm: EncryptionAlgorithm,
+    ) -> ClientResult<EncryptionBoxHandle>;
+}
+
+pub type KeyProviderRef = Arc<dyn KeyProvider + Send + Sync>;
+
+/// Source of user keys for SigningBoxInput and EncryptionBoxInput interfaces.
+#[derive(Serialize, Deserialize, Clone, Debug, ApiType)]
+#[serde(tag = "type")]
+pub enum KeyProviderConfig {
+    /// Keys are taken from the list of key pairs.
+    KeyPairs {
+        /// Key pairs available to user.
+        keys: Vec<KeyPair>,
+    },
+    /// Key requests are routed to application (JSON API only).
+    App,
+}
+
+/// Key provider backed by a list of key pairs.
+pub struct KeyPairProvider {
+    ton: TonClient,
+    keys: Vec<KeyPair>,
+}
+
+impl KeyPairProvider {
+    pub fn new(ton: TonClient, keys: Vec<KeyPair>) -> Self {
+        Self { ton, keys }
+    }
+
+    fn find(&self, public: &str) -> ClientResult<&KeyPair> {
+        self.keys
+            .iter()
+            .find(|k| k.public == public)
+            .ok_or_else(|| Error::key_not_found(public))
+    }
+}
+
+#[async_trait::async_trait]
+impl KeyProvider for KeyPairProvider {
+    async fn list_keys(&self) -> ClientResult<Vec<String>> {
+        Ok(self.keys.iter().map(|k| k.public.clone()).collect())
+    }
+
+    async fn get_signing_box(&self, public: &str) -> ClientResult<SigningBoxHandle> {
+        let keys = self.find(public)?.clone();
+        Ok(get_signing_box(self.ton.clone(), keys).await?.handle)
+    }
+
+    async fn get_encryption_box(
+        &self,
+        public: &str,
+        algorithm: EncryptionAlgorithm,
+    ) -> ClientResult<EncryptionBoxHandle> {
+        let secret = self.find(public)?.secret.clone();
+        let algorithm = match algorithm {
+            EncryptionAlgorithm::AES(mut params) => {
+                params.key = secret;
+                EncryptionAlgorithm::AES(params)
+            }
+            EncryptionAlgorithm::ChaCha20(mut params) => {
+                params.key = secret;
+                EncryptionAlgorithm::ChaCha20(params)
+            }
+            EncryptionAlgorithm::NaclBox(mut params) => {
+                params.secret = secret;
+                EncryptionAlgorithm::NaclBox(params)
+            }
+            EncryptionAlgorithm::NaclSecretBox(mut params) => {
+                params.key = secret;
+                EncryptionAlgorithm::NaclSecretBox(params)
+            }
+        };
+        Ok(create_encryption_box(
+            self.ton.clone(),
+            ParamsOfCreateEncryptionBox { algorithm },
+        )
+        .await?
+        .handle)
+    }
+}
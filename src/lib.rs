@@ -1,5 +1,6 @@
 mod action;
 mod activity;
+mod boxes;
 mod bridge_api;
 mod browser;
 mod builtin_interfaces;
@@ -12,12 +13,17 @@ pub mod errors;
 mod helpers;
 mod info;
 mod json_interface;
+mod keys;
+mod network_policy;
 pub mod prelude;
 mod routines;
 mod run_output;
 mod sdk_prelude;
+mod storage;
+mod subscriptions;
+mod wallet;
 
-use crate::bridge_api::{fetch, remove, send, start};
+use crate::bridge_api::{fetch, get_boxes, remove, send, send_collection_event, start};
 use crate::common::{DInfo, Deserialize, Error, Serialize};
 pub use crate::json_interface::*;
 
@@ -1,14 +1,26 @@
 pub use crate::action::DAction;
 pub use crate::activity::{DebotActivity, Spending};
-pub use crate::browser::{BrowserCallbacks, BrowserRef, FetchHeader, FetchResponse, WaitForTransactionParams, LogLevel};
+pub use crate::boxes::LiveBoxes;
+pub use crate::browser::{
+    BrowserCallbacks, BrowserRef, DateTimeKind, FetchHeader, FetchRequest, FetchResponse, LogLevel,
+    MenuItem, WaitForTransactionParams,
+};
 pub use crate::builtin_interfaces::{
     decode_answer_id, get_arg, get_bool_arg, get_num_arg, BuiltinInterfaces, DebotInterface,
-    DebotInterfaceExecutor, InterfaceResult,
+    DebotInterfaceExecutor, InterfaceResult, UserProfile,
 };
 pub use crate::context::{STATE_CURRENT, STATE_EXIT, STATE_PREV, STATE_ZERO};
 pub use crate::debot_abi::DEBOT_ABI;
 pub use crate::dengine::DEngine;
 pub use crate::errors::{Error, ErrorCode};
+pub use crate::keys::{KeyPairProvider, KeyProvider, KeyProviderConfig, KeyProviderRef};
+pub use crate::network_policy::NetworkPolicy;
+pub use crate::storage::{
+    FileStorageProvider, MemoryStorageProvider, StorageBackend, StorageConfig, StorageProvider,
+    StorageProviderRef, DEFAULT_STORAGE_QUOTA,
+};
+pub use crate::subscriptions::{CollectionEvent, DebotEvent, EventReceiver, EventSink};
+pub use crate::wallet::WalletConfig;
 pub use crate::{DebotInfo, DEBOT_WC};
 pub use ton_client::abi::{
     Abi, AbiContract, AbiData, AbiEvent, AbiFunction, AbiHandle, AbiParam, DecodedMessageBody,
@@ -16,9 +28,11 @@ pub use ton_client::abi::{
 };
 pub use ton_client::crypto::{EncryptionBoxHandle, EncryptionBoxInfo, SigningBoxHandle};
 pub use ton_client::net::{
-    MessageNode, OrderBy, ParamsOfQuery, ParamsOfQueryCollection, ParamsOfQueryTransactionTree,
-    ParamsOfWaitForCollection, ResultOfQuery, ResultOfQueryCollection,
-    ResultOfQueryTransactionTree, ResultOfWaitForCollection, SortDirection, TransactionNode,
+    AggregationFn, FieldAggregation, MessageNode, OrderBy, ParamsOfAggregateCollection,
+    ParamsOfQuery, ParamsOfQueryCollection, ParamsOfQueryTransactionTree,
+    ParamsOfSubscribeCollection, ParamsOfWaitForCollection, ResultOfAggregateCollection,
+    ResultOfQuery, ResultOfQueryCollection, ResultOfQueryTransactionTree,
+    ResultOfWaitForCollection, SortDirection, TransactionNode,
 };
 pub use ton_client::processing::{
     DecodedOutput, ParamsOfWaitForTransaction, ResultOfProcessMessage,
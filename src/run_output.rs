@@ -1,4 +1,5 @@
 use crate::action::DAction;
+use crate::calltype::{ExtCallKind, Metadata};
 use crate::common::*;
 use crate::sdk_prelude::*;
 use crate::{JsonValue, DEBOT_WC};
@@ -28,7 +29,7 @@ impl RunOutput {
         };
         while let Some(msg_base64) = msgs.pop() {
             let msg: Message = deserialize_object_from_base64(&msg_base64, "message")?.object;
-            output.filter_msg(msg, msg_base64);
+            output.filter_msg(msg, msg_base64)?;
         }
 
         Ok(output)
@@ -55,12 +56,15 @@ impl RunOutput {
         self.calls.pop_front()
     }
 
-    fn filter_msg(&mut self, msg: Message, msg_base64: String) {
+    fn filter_msg(&mut self, msg: Message, msg_base64: String) -> ClientResult<()> {
         let msg = (&msg, msg_base64);
-        self.filter_interface_call(msg)
-            .and_then(|msg| self.filter_invoke_call(msg))
-            .and_then(|msg| self.filter_external_call(msg))
-            .and_then(|msg| self.filter_getmethod_call(msg));
+        let msg = self
+            .filter_interface_call(msg)
+            .and_then(|msg| self.filter_invoke_call(msg));
+        if let Some(msg) = msg {
+            self.filter_external_inbound_msg(msg)?;
+        }
+        Ok(())
     }
 
     fn filter_interface_call<'a>(
@@ -114,50 +118,26 @@ impl RunOutput {
         Some(msg)
     }
 
-    fn filter_external_call<'a>(
-        &mut self,
-        msg: (&'a Message, String),
-    ) -> Option<(&'a Message, String)> {
-        self.filter_external_inbound_msg(msg, true)
-    }
-
-    fn filter_getmethod_call<'a>(
-        &mut self,
-        msg: (&'a Message, String),
-    ) -> Option<(&'a Message, String)> {
-        self.filter_external_inbound_msg(msg, false)
-    }
-
-    fn filter_external_inbound_msg<'a>(
-        &mut self,
-        msg: (&'a Message, String),
-        call_or_get: bool,
-    ) -> Option<(&'a Message, String)> {
-        if msg.0.is_inbound_external() {
-            if let Some(body_slice) = msg.0.body() {
-                // TODO: currently using an unreliable way to
-                // distinguish a get-method call from external call.
-                // Most accurate method - to check flags in src address.
-                let mut body_slice = body_slice;
-                let dest = msg
-                    .0
-                    .header()
-                    .get_dst_address()
-                    .map(|x| x.to_string())
-                    .unwrap_or_default();
-                if let Ok(bit) = body_slice.get_next_bit() {
-                    if call_or_get && bit {
-                        self.calls
-                            .push_back(DebotCallType::External { msg: msg.1, dest });
-                        return None;
-                    } else if !call_or_get && !bit {
-                        self.calls
-                            .push_back(DebotCallType::GetMethod { msg: msg.1, dest });
-                        return None;
-                    }
-                }
-            }
+    fn filter_external_inbound_msg(&mut self, msg: (&Message, String)) -> ClientResult<()> {
+        if !msg.0.is_inbound_external() {
+            return Ok(());
         }
-        Some(msg)
+        let dest = msg
+            .0
+            .header()
+            .get_dst_address()
+            .map(|x| x.to_string())
+            .unwrap_or_default();
+        // malformed metadata is an error in DeBot, don't drop such message silently.
+        let kind = Metadata::from_message(msg.0)?.call_kind(msg.0.body())?;
+        match kind {
+            ExtCallKind::External => self
+                .calls
+                .push_back(DebotCallType::External { msg: msg.1, dest }),
+            ExtCallKind::GetMethod => self
+                .calls
+                .push_back(DebotCallType::GetMethod { msg: msg.1, dest }),
+        }
+        Ok(())
     }
 }
@@ -29,9 +29,10 @@ pub use ton_client::crypto::{
 pub use ton_client::encoding::{decode_abi_bigint, decode_abi_number};
 pub use ton_client::error::{ClientError, ClientResult};
 pub use ton_client::net::{
-    NetworkConfig, OrderBy, ParamsOfQuery,
+    AggregationFn, FieldAggregation, NetworkConfig, OrderBy, ParamsOfAggregateCollection, ParamsOfQuery,
+    ParamsOfSubscribeCollection,
     ParamsOfQueryCollection, ParamsOfQueryTransactionTree, ParamsOfWaitForCollection,
-    ResultOfQuery, ResultOfQueryCollection, ResultOfWaitForCollection, SortDirection, ResultOfQueryTransactionTree,
+    ResultOfAggregateCollection, ResultOfQuery, ResultOfQueryCollection, ResultOfWaitForCollection, SortDirection, ResultOfQueryTransactionTree,
 };
 pub use ton_client::processing::{
     process_message, ParamsOfProcessMessage, ParamsOfSendMessage,
@@ -0,0 +1,284 @@
+use crate::common::Error;
+use crate::sdk_prelude::*;
+use api_derive::ApiType;
+use serde_derive::{Deserialize, Serialize};
+use std::collections::{BTreeMap, HashMap};
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+/// Default maximal size of DeBot data (keys and values) in bytes.
+pub const DEFAULT_STORAGE_QUOTA: u32 = 64 * 1024;
+
+/// Key-value storage used by Storage interface.
+///
+/// Data of each DeBot is kept in a separate namespace (DeBot address in raw form).
+#[async_trait::async_trait]
+pub trait StorageProvider {
+    /// Returns value of the key or `None` if key is not found.
+    async fn get(&self, namespace: &str, key: &str) -> ClientResult<Option<String>>;
+    /// Sets value of the key if total size of keys and values of namespace with the new
+    /// value doesn't exceed `quota` bytes. Returns false if quota is exceeded.
+    /// Check and change must be atomic.
+    async fn set_with_quota(
+        &self,
+        namespace: &str,
+        key: &str,
+        value: String,
+        quota: usize,
+    ) -> ClientResult<bool>;
+    /// Removes the key. Removing of missing key is not an error.
+    async fn remove(&self, namespace: &str, key: &str) -> ClientResult<()>;
+    /// Returns all keys of namespace.
+    async fn keys(&self, namespace: &str) -> ClientResult<Vec<String>>;
+}
+
+pub type StorageProviderRef = Arc<dyn StorageProvider + Send + Sync>;
+
+/// Storage backend used by engine.
+#[derive(Serialize, Deserialize, Clone, Debug, ApiType)]
+#[serde(tag = "type")]
+pub enum StorageBackend {
+    /// Data is kept in memory and lost when DeBot is removed.
+    Memory,
+    /// Data is kept in JSON file.
+    File {
+        /// Path to file.
+        path: String,
+    },
+    /// Storage requests are routed to application (JSON API only).
+    App,
+}
+
+impl Default for StorageBackend {
+    fn default() -> Self {
+        StorageBackend::Memory
+    }
+}
+
+/// Configuration of Storage interface.
+#[derive(Serialize, Deserialize, Clone, Debug, ApiType, Default)]
+pub struct StorageConfig {
+    /// Storage backend.
+    #[serde(default)]
+    pub backend: StorageBackend,
+    /// Maximal size of DeBot data (keys and values) in bytes. Default is 64 KiB.
+    pub quota: Option<u32>,
+}
+
+type Namespaces = HashMap<String, BTreeMap<String, String>>;
+
+/// Sets value of the key if namespace size with it doesn't exceed quota.
+fn set_checked(
+    data: &mut Namespaces,
+    namespace: &str,
+    key: &str,
+    value: String,
+    quota: usize,
+) -> bool {
+    let ns = data.entry(namespace.to_owned()).or_default();
+    let usage: usize = ns
+        .iter()
+        .filter(|(k, _)| k.as_str() != key)
+        .map(|(k, v)| k.len() + v.len())
+        .sum();
+    if usage + key.len() + value.len() > quota {
+        if ns.is_empty() {
+            data.remove(namespace);
+        }
+        return false;
+    }
+    ns.insert(key.to_owned(), value);
+    true
+}
+
+/// In-memory storage.
+#[derive(Default)]
+pub struct MemoryStorageProvider {
+    data: Mutex<Namespaces>,
+}
+
+impl MemoryStorageProvider {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait::async_trait]
+impl StorageProvider for MemoryStorageProvider {
+    async fn get(&self, namespace: &str, key: &str) -> ClientResult<Option<String>> {
+        let data = self.data.lock().unwrap();
+        Ok(data.get(namespace).and_then(|ns| ns.get(key)).cloned())
+    }
+
+    async fn set_with_quota(
+        &self,
+        namespace: &str,
+        key: &str,
+        value: String,
+        quota: usize,
+    ) -> ClientResult<bool> {
+        let mut data = self.data.lock().unwrap();
+        Ok(set_checked(&mut data, namespace, key, value, quota))
+    }
+
+    async fn remove(&self, namespace: &str, key: &str) -> ClientResult<()> {
+        let mut data = self.data.lock().unwrap();
+        if let Some(ns) = data.get_mut(namespace) {
+            ns.remove(key);
+        }
+        Ok(())
+    }
+
+    async fn keys(&self, namespace: &str) -> ClientResult<Vec<String>> {
+        let data = self.data.lock().unwrap();
+        Ok(data
+            .get(namespace)
+            .map(|ns| ns.keys().cloned().collect())
+            .unwrap_or_default())
+    }
+}
+
+/// Storage kept in JSON file `{"<namespace>": {"<key>": "<value>"}}`.
+///
+/// File is read on each request and replaced on each change. Engines using
+/// the same file must share one provider, otherwise concurrent changes are lost.
+pub struct FileStorageProvider {
+    path: PathBuf,
+    lock: Mutex<()>,
+}
+
+impl FileStorageProvider {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            lock: Mutex::new(()),
+        }
+    }
+
+    fn load(&self) -> ClientResult<Namespaces> {
+        match std::fs::read_to_string(&self.path) {
+            Ok(content) => serde_json::from_str(&content).map_err(|e| {
+                Error::storage_failed(format!("invalid file {}: {e}", self.path.display()))
+            }),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(Namespaces::new()),
+            Err(e) => Err(Error::storage_failed(format!(
+                "failed to read {}: {e}",
+                self.path.display()
+            ))),
+        }
+    }
+
+    /// Writes data to temporary file and renames it over the storage file,
+    /// so the file is never left partially written.
+    fn save(&self, data: &Namespaces) -> ClientResult<()> {
+        let content = serde_json::to_string_pretty(data).map_err(Error::storage_failed)?;
+        let mut tmp_path = self.path.clone().into_os_string();
+        tmp_path.push(".tmp");
+        let tmp_path = PathBuf::from(tmp_path);
+        std::fs::write(&tmp_path, content)
+            .and_then(|_| std::fs::rename(&tmp_path, &self.path))
+            .map_err(|e| {
+                let _ = std::fs::remove_file(&tmp_path);
+                Error::storage_failed(format!("failed to write {}: {e}", self.path.display()))
+            })
+    }
+
+    fn update(&self, f: impl FnOnce(&mut Namespaces)) -> ClientResult<()> {
+        let _guard = self.lock.lock().unwrap();
+        let mut data = self.load()?;
+        f(&mut data);
+        self.save(&data)
+    }
+}
+
+#[async_trait::async_trait]
+impl StorageProvider for FileStorageProvider {
+    async fn get(&self, namespace: &str, key: &str) -> ClientResult<Option<String>> {
+        let _guard = self.lock.lock().unwrap();
+        Ok(self.load()?.get(namespace).and_then(|ns| ns.get(key)).cloned())
+    }
+
+    async fn set_with_quota(
+        &self,
+        namespace: &str,
+        key: &str,
+        value: String,
+        quota: usize,
+    ) -> ClientResult<bool> {
+        let _guard = self.lock.lock().unwrap();
+        let mut data = self.load()?;
+        if !set_checked(&mut data, namespace, key, value, quota) {
+            return Ok(false);
+        }
+        self.save(&data)?;
+        Ok(true)
+    }
+
+    async fn remove(&self, namespace: &str, key: &str) -> ClientResult<()> {
+        self.update(|data| {
+            if let Some(ns) = data.get_mut(namespace) {
+                ns.remove(key);
+                if ns.is_empty() {
+                    data.remove(namespace);
+                }
+            }
+        })
+    }
+
+    async fn keys(&self, namespace: &str) -> ClientResult<Vec<String>> {
+        let _guard = self.lock.lock().unwrap();
+        Ok(self
+            .load()?
+            .get(namespace)
+            .map(|ns| ns.keys().cloned().collect())
+            .unwrap_or_default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::executor::block_on;
+
+    fn check_provider(storage: &dyn StorageProvider) {
+        block_on(async {
+            let set = |ns: &'static str, key: &'static str, value: &str, quota: usize| {
+                storage.set_with_quota(ns, key, value.to_owned(), quota)
+            };
+            assert!(set("0:01", "b", "2", 2).await.unwrap());
+            assert!(set("0:01", "a", "1", 4).await.unwrap());
+            assert!(set("0:02", "a", "other", 6).await.unwrap());
+            assert_eq!(storage.get("0:01", "a").await.unwrap(), Some("1".to_owned()));
+            assert_eq!(storage.keys("0:01").await.unwrap(), vec!["a", "b"]);
+            // replaced value doesn't count
+            assert!(set("0:01", "a", "12", 5).await.unwrap());
+            assert!(!set("0:01", "a", "123", 5).await.unwrap());
+            assert!(!set("0:03", "a", "1", 1).await.unwrap());
+            assert_eq!(storage.get("0:01", "a").await.unwrap(), Some("12".to_owned()));
+            assert!(storage.keys("0:03").await.unwrap().is_empty());
+            storage.remove("0:01", "a").await.unwrap();
+            storage.remove("0:01", "missing").await.unwrap();
+            assert_eq!(storage.get("0:01", "a").await.unwrap(), None);
+            assert_eq!(storage.keys("0:02").await.unwrap(), vec!["a"]);
+            assert!(storage.keys("0:03").await.unwrap().is_empty());
+        });
+    }
+
+    #[test]
+    fn test_memory_storage() {
+        check_provider(&MemoryStorageProvider::new());
+    }
+
+    #[test]
+    fn test_file_storage() {
+        let path = std::env::temp_dir().join(format!("debot-storage-{}.json", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        check_provider(&FileStorageProvider::new(&path));
+        let reopened = FileStorageProvider::new(&path);
+        assert_eq!(block_on(reopened.get("0:01", "b")).unwrap(), Some("2".to_owned()));
+        let mut tmp_path = path.clone().into_os_string();
+        tmp_path.push(".tmp");
+        assert!(!PathBuf::from(tmp_path).exists());
+        let _ = std::fs::remove_file(&path);
+    }
+}
@@ -0,0 +1,185 @@
+use crate::JsonValue;
+use api_derive::ApiType;
+use futures::channel::mpsc::{unbounded, UnboundedReceiver, UnboundedSender};
+use futures::StreamExt;
+use serde_derive::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+use std::sync::{Arc, Mutex};
+
+/// Collection update delivered to DeBot subscription.
+#[derive(Serialize, Deserialize, Clone, Debug, ApiType, Default)]
+pub struct CollectionEvent {
+    /// Subscription ID returned to DeBot by Query interface.
+    pub subscription_id: u32,
+    /// Updated object of collection.
+    pub result: JsonValue,
+}
+
+/// Event waiting to be handled by engine.
+#[derive(Clone, Debug)]
+pub enum DebotEvent {
+    /// Collection update of DeBot subscription.
+    Collection(CollectionEvent),
+    /// Delayed answer of DeBot interface (e.g. Timer interface).
+    Answer {
+        /// Address of interface in `<wc>:<interface id>` form.
+        source: String,
+        /// ID of DeBot function receiving answer.
+        answer_id: u32,
+        /// Answer arguments.
+        params: JsonValue,
+    },
+}
+
+/// Sink used by browser to deliver collection updates of one subscription.
+#[derive(Clone)]
+pub struct EventSink {
+    subscription_id: u32,
+    sender: UnboundedSender<DebotEvent>,
+}
+
+impl EventSink {
+    pub fn subscription_id(&self) -> u32 {
+        self.subscription_id
+    }
+
+    /// Queues update for DeBot. Returns false if engine doesn't accept events
+    /// anymore, in that case browser should cancel subscription.
+    pub fn send(&self, result: JsonValue) -> bool {
+        self.sender
+            .unbounded_send(DebotEvent::Collection(CollectionEvent {
+                subscription_id: self.subscription_id,
+                result,
+            }))
+            .is_ok()
+    }
+}
+
+/// Stream of events waiting to be handled by engine.
+#[derive(Clone)]
+pub struct EventReceiver(Arc<tokio::sync::Mutex<UnboundedReceiver<DebotEvent>>>);
+
+impl EventReceiver {
+    /// Waits for the next event. Returns `None` when engine is closed.
+    pub async fn next(&self) -> Option<DebotEvent> {
+        self.0.lock().await.next().await
+    }
+}
+
+/// Active DeBot subscriptions and pending engine events.
+pub(crate) struct Subscriptions {
+    next_id: AtomicU32,
+    /// Subscription ID -> ID of DeBot function handling updates.
+    callbacks: Mutex<HashMap<u32, u32>>,
+    sender: UnboundedSender<DebotEvent>,
+    receiver: EventReceiver,
+    /// Set when receiver is taken, i.e. somebody dispatches events.
+    listened: AtomicBool,
+}
+
+impl Subscriptions {
+    pub fn new() -> Self {
+        let (sender, receiver) = unbounded();
+        Self {
+            next_id: AtomicU32::new(1),
+            callbacks: Mutex::new(HashMap::new()),
+            sender,
+            receiver: EventReceiver(Arc::new(tokio::sync::Mutex::new(receiver))),
+            listened: AtomicBool::new(false),
+        }
+    }
+
+    pub fn add(&self, callback_id: u32) -> EventSink {
+        let subscription_id = self.next_id.fetch_add(1, Ordering::Relaxed);
+        self.callbacks.lock().unwrap().insert(subscription_id, callback_id);
+        EventSink {
+            subscription_id,
+            sender: self.sender.clone(),
+        }
+    }
+
+    pub fn remove(&self, subscription_id: u32) -> bool {
+        self.callbacks.lock().unwrap().remove(&subscription_id).is_some()
+    }
+
+    /// Returns ID of DeBot function handling updates of subscription.
+    pub fn callback(&self, subscription_id: u32) -> Option<u32> {
+        self.callbacks.lock().unwrap().get(&subscription_id).copied()
+    }
+
+    pub fn ids(&self) -> Vec<u32> {
+        self.callbacks.lock().unwrap().keys().copied().collect()
+    }
+
+    /// Queues update received from outside of browser callbacks (e.g. JSON API).
+    pub fn push(&self, event: CollectionEvent) -> bool {
+        self.callback(event.subscription_id).is_some()
+            && self.sender.unbounded_send(DebotEvent::Collection(event)).is_ok()
+    }
+
+    /// Queues delayed answer of DeBot interface.
+    pub fn push_answer(&self, source: String, answer_id: u32, params: JsonValue) -> bool {
+        self.sender
+            .unbounded_send(DebotEvent::Answer {
+                source,
+                answer_id,
+                params,
+            })
+            .is_ok()
+    }
+
+    pub fn events(&self) -> EventReceiver {
+        self.listened.store(true, Ordering::Relaxed);
+        self.receiver.clone()
+    }
+
+    /// Returns true if events are dispatched, so delayed answers reach DeBot.
+    pub fn is_listened(&self) -> bool {
+        self.listened.load(Ordering::Relaxed)
+    }
+
+    /// Stops accepting events. Pending events are still returned by receiver.
+    pub fn close(&self) {
+        self.callbacks.lock().unwrap().clear();
+        self.sender.close_channel();
+    }
+}
+
+impl Drop for Subscriptions {
+    fn drop(&mut self) {
+        self.sender.close_channel();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::executor::block_on;
+    use serde_json::json;
+
+    #[test]
+    fn test_close_stops_events() {
+        let subscriptions = Subscriptions::new();
+        let sink = subscriptions.add(1);
+        let id = sink.subscription_id();
+        assert!(sink.send(json!({ "a": 1 })));
+        subscriptions.close();
+
+        assert!(subscriptions.ids().is_empty());
+        assert!(!sink.send(json!({})));
+        let event = CollectionEvent {
+            subscription_id: id,
+            result: json!({}),
+        };
+        assert!(!subscriptions.push(event));
+        assert!(!subscriptions.push_answer(String::new(), 0, json!({})));
+        // pending update is still delivered, then stream ends
+        let events = subscriptions.events();
+        assert!(matches!(
+            block_on(events.next()),
+            Some(DebotEvent::Collection(e)) if e.subscription_id == id
+        ));
+        assert!(block_on(events.next()).is_none());
+    }
+}
@@ -0,0 +1,304 @@
+use crate::boxes::BoxRegistry;
+use crate::browser::WaitForTransactionParams;
+use crate::calltype::{emulate_message, signer_info};
+use crate::common::*;
+use crate::dengine::EMPTY_CELL;
+use crate::sdk_prelude::*;
+use api_derive::ApiType;
+
+// Functions common for SafeMultisig and SetcodeMultisig wallets,
+// so wallet type doesn't need to be configured.
+const MULTISIG_ABI: &str = r#"
+{
+	"ABI version": 2,
+	"header": ["pubkey", "time", "expire"],
+	"functions": [
+		{
+			"name": "sendTransaction",
+			"inputs": [
+				{"name":"dest","type":"address"},
+				{"name":"value","type":"uint128"},
+				{"name":"bounce","type":"bool"},
+				{"name":"flags","type":"uint8"},
+				{"name":"payload","type":"cell"}
+			],
+			"outputs": [
+			]
+		},
+		{
+			"name": "submitTransaction",
+			"inputs": [
+				{"name":"dest","type":"address"},
+				{"name":"value","type":"uint128"},
+				{"name":"bounce","type":"bool"},
+				{"name":"allBalance","type":"bool"},
+				{"name":"payload","type":"cell"}
+			],
+			"outputs": [
+				{"name":"transId","type":"uint64"}
+			]
+		}
+	],
+	"data": [
+	],
+	"events": [
+	]
+}
+"#;
+
+// Sender pays forward fees separately and errors in action phase are ignored.
+const SEND_FLAGS: u8 = 3;
+
+/// User wallet used by DeBot Engine to send internal messages on behalf of DeBot.
+#[derive(Serialize, Deserialize, Clone, Debug, ApiType, Default)]
+pub struct WalletConfig {
+    /// Wallet address.
+    pub address: String,
+    /// Wallet has several custodians. Transactions are created with
+    /// `submitTransaction` and wait for confirmations of other custodians.
+    /// Otherwise `sendTransaction` is used.
+    #[serde(default)]
+    pub multi_custodian: bool,
+    /// Signing box of wallet custodian. If not set, engine requests it from browser.
+    pub signing_box: Option<SigningBoxHandle>,
+}
+
+/// Internal call of DeBot routed through user wallet.
+pub(crate) struct WalletCall {
+    browser: BrowserRef,
+    ton: TonClient,
+    wallet: WalletConfig,
+    dest: String,
+    value: u64,
+    bounce: bool,
+    payload: String,
+    boxes: Arc<BoxRegistry>,
+}
+
+impl WalletCall {
+    /// Creates wallet call from internal message built by DeBot.
+    pub async fn new(
+        browser: BrowserRef,
+        ton: TonClient,
+        wallet: WalletConfig,
+        msg: String,
+        boxes: Arc<BoxRegistry>,
+    ) -> ClientResult<Self> {
+        let parsed = parse_message(ton.clone(), ParamsOfParse { boc: msg })?.parsed;
+        if parsed["msg_type"].as_u64() != Some(0) {
+            return Err(Error::invalid_msg("not an internal message"));
+        }
+        let dest = parsed["dst"]
+            .as_str()
+            .ok_or_else(|| Error::invalid_msg("failed to parse dst address"))?
+            .to_owned();
+        let value = decode_abi_number(parsed["value"].as_str().unwrap_or("0"))?;
+        let bounce = parsed["bounce"].as_bool().unwrap_or(false);
+        let payload = parsed["body"].as_str().unwrap_or(EMPTY_CELL).to_owned();
+        Ok(Self {
+            browser,
+            ton,
+            wallet,
+            dest,
+            value,
+            bounce,
+            payload,
+            boxes,
+        })
+    }
+
+    /// Sends message to wallet and waits for wallet transaction.
+    ///
+    /// Returns id of the external message sent to wallet.
+    pub async fn execute(&self) -> ClientResult<String> {
+        let signer = Signer::SigningBox {
+            handle: match self.wallet.signing_box.clone() {
+                Some(handle) => handle,
+                None => {
+                    let handle = self
+                        .browser
+                        .get_signing_box()
+                        .await
+                        .map_err(Error::external_call_failed)?;
+                    self.boxes.add_signing_box(&handle);
+                    handle
+                }
+            },
+        };
+        let message = encode_wallet_message(
+            self.ton.clone(),
+            &self.wallet,
+            &self.dest,
+            self.value,
+            self.bounce,
+            &self.payload,
+            signer.clone(),
+        )
+        .await?;
+        let activity = self.emulate_chain(message.clone(), &signer).await?;
+        if !self.browser.approve(activity).await? {
+            return Err(Error::operation_rejected());
+        }
+        let result = self.browser.send_message(message.clone()).await?;
+        self.browser
+            .wait_for_transaction(WaitForTransactionParams {
+                abi: None,
+                message: message.clone(),
+                shard_block_id: result.shard_block_id,
+                send_events: true,
+                sending_endpoints: Some(result.sending_endpoints),
+            })
+            .await?;
+        Ok(get_boc_hash(self.ton.clone(), ParamsOfGetBocHash { boc: message })?.hash)
+    }
+
+    /// Emulates wallet transaction and transaction of the destination account
+    /// initiated by the wallet.
+    async fn emulate_chain(&self, message: String, signer: &Signer) -> ClientResult<DebotActivity> {
+        let wallet_state =
+            DEngine::fetch_state_with_browser(self.browser.clone(), self.wallet.address.clone())
+                .await
+                .map_err(Error::external_call_failed)?;
+        let wallet_tx = emulate_message(self.ton.clone(), message.clone(), wallet_state).await?;
+        let mut fee = wallet_tx.fee;
+        let mut out = wallet_tx.out;
+
+        // Transaction of multi-custodian wallet can wait for confirmations of other
+        // custodians, so call of destination account is not emulated.
+        if !self.wallet.multi_custodian {
+            let dest_state =
+                DEngine::fetch_state_with_browser(self.browser.clone(), self.dest.clone()).await;
+            // Destination account can be absent, e.g. if wallet transfers funds to new account.
+            if let Ok(dest_state) = dest_state {
+                for out_msg in wallet_tx.out_messages {
+                    let parsed =
+                        parse_message(self.ton.clone(), ParamsOfParse { boc: out_msg.clone() })?
+                            .parsed;
+                    if parsed["dst"].as_str() == Some(self.dest.as_str()) {
+                        let dest_tx =
+                            emulate_message(self.ton.clone(), out_msg, dest_state.clone()).await?;
+                        fee += dest_tx.fee;
+                        out.extend(dest_tx.out);
+                    }
+                }
+            }
+        }
+
+        let (signing_box_handle, signkey) = signer_info(self.ton.clone(), signer).await?;
+        Ok(DebotActivity::Transaction {
+            msg: message,
+            dst: self.wallet.address.clone(),
+            out,
+            fee,
+            setcode: false,
+            signkey,
+            signing_box_handle,
+        })
+    }
+}
+
+/// Encodes wallet external message transferring `value` with `payload` to `dest`.
+async fn encode_wallet_message(
+    ton: TonClient,
+    wallet: &WalletConfig,
+    dest: &str,
+    value: u64,
+    bounce: bool,
+    payload: &str,
+    signer: Signer,
+) -> ClientResult<String> {
+    let (func, input) = if wallet.multi_custodian {
+        (
+            "submitTransaction",
+            json!({
+                "dest": dest,
+                "value": value.to_string(),
+                "bounce": bounce,
+                "allBalance": false,
+                "payload": payload,
+            }),
+        )
+    } else {
+        (
+            "sendTransaction",
+            json!({
+                "dest": dest,
+                "value": value.to_string(),
+                "bounce": bounce,
+                "flags": SEND_FLAGS,
+                "payload": payload,
+            }),
+        )
+    };
+    let result = encode_message(
+        ton,
+        ParamsOfEncodeMessage {
+            abi: Abi::Json(MULTISIG_ABI.to_owned()),
+            address: Some(wallet.address.clone()),
+            call_set: CallSet::some_with_function_and_input(func, input),
+            signer,
+            ..Default::default()
+        },
+    )
+    .await?;
+    Ok(result.message)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures::executor::block_on;
+    use ton_client::crypto::generate_random_sign_keys;
+
+    const WALLET: &str = "0:1111111111111111111111111111111111111111111111111111111111111111";
+    const DEST: &str = "0:2222222222222222222222222222222222222222222222222222222222222222";
+
+    fn encode_and_decode(multi_custodian: bool) -> (String, JsonValue) {
+        let ton: TonClient = Arc::new(ClientContext::new(ClientConfig::default()).unwrap());
+        let keys = generate_random_sign_keys(ton.clone()).unwrap();
+        let wallet = WalletConfig {
+            address: WALLET.to_owned(),
+            multi_custodian,
+            signing_box: None,
+        };
+        let message = block_on(encode_wallet_message(
+            ton.clone(),
+            &wallet,
+            DEST,
+            1_500_000_000,
+            true,
+            EMPTY_CELL,
+            Signer::Keys { keys },
+        ))
+        .unwrap();
+        let decoded = decode_message(
+            ton,
+            ParamsOfDecodeMessage {
+                abi: Abi::Json(MULTISIG_ABI.to_owned()),
+                message,
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        (decoded.name, decoded.value.unwrap())
+    }
+
+    #[test]
+    fn test_single_custodian_message() {
+        let (name, value) = encode_and_decode(false);
+        assert_eq!(name, "sendTransaction");
+        assert_eq!(value["dest"], DEST);
+        assert_eq!(value["value"], "1500000000");
+        assert_eq!(value["bounce"], true);
+        assert_eq!(value["flags"], SEND_FLAGS.to_string());
+    }
+
+    #[test]
+    fn test_multi_custodian_message() {
+        let (name, value) = encode_and_decode(true);
+        assert_eq!(name, "submitTransaction");
+        assert_eq!(value["dest"], DEST);
+        assert_eq!(value["value"], "1500000000");
+        assert_eq!(value["allBalance"], false);
+    }
+}
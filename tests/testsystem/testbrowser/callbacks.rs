@@ -3,11 +3,12 @@ use super::config::Config;
 use super::helpers::TonClient;
 use super::term_browser::{input, terminal_input};
 use dengine::prelude::*;
-use std::collections::VecDeque;
-use std::io;
+use std::collections::{HashMap, VecDeque};
+use std::io::{self, Read};
 use std::sync::{Arc, RwLock};
 use ton_client::crypto::{ParamsOfEncryptionBoxGetInfo, RegisteredSigningBox, SigningBoxHandle};
 use ton_client::error::ClientResult;
+use ton_client::net::{ResultOfSubscribeCollection, ResultOfSubscription};
 use ton_client::processing::ParamsOfSendMessage;
 
 #[derive(Default)]
@@ -16,6 +17,7 @@ struct ActiveState {
     active_actions: Vec<DAction>,
     msg_queue: VecDeque<String>,
     outputs: Vec<String>,
+    subscriptions: HashMap<u32, ResultOfSubscribeCollection>,
 }
 
 pub(super) struct Callbacks {
@@ -189,6 +191,38 @@ impl BrowserCallbacks for Callbacks {
     ) -> ClientResult<ResultOfWaitForCollection> {
         ton_client::net::wait_for_collection(self.client.clone(), params).await
     }
+    async fn aggregate_collection(
+        &self,
+        params: ParamsOfAggregateCollection,
+    ) -> ClientResult<ResultOfAggregateCollection> {
+        ton_client::net::aggregate_collection(self.client.clone(), params).await
+    }
+    async fn subscribe_collection(
+        &self,
+        params: ParamsOfSubscribeCollection,
+        events: EventSink,
+    ) -> ClientResult<()> {
+        let subscription_id = events.subscription_id();
+        let callback = move |result: ClientResult<ResultOfSubscription>| {
+            let events = events.clone();
+            async move {
+                if let Ok(result) = result {
+                    events.send(result.result);
+                }
+            }
+        };
+        let handle =
+            ton_client::net::subscribe_collection(self.client.clone(), params, callback).await?;
+        self.state.write().unwrap().subscriptions.insert(subscription_id, handle);
+        Ok(())
+    }
+    async fn unsubscribe_collection(&self, subscription_id: u32) -> ClientResult<()> {
+        let handle = self.state.write().unwrap().subscriptions.remove(&subscription_id);
+        match handle {
+            Some(handle) => ton_client::net::unsubscribe(self.client.clone(), handle).await,
+            None => Ok(()),
+        }
+    }
     async fn wait_for_transaction(
         &self,
         params: WaitForTransactionParams,
@@ -233,4 +267,134 @@ impl BrowserCallbacks for Callbacks {
         .await?;
         Ok(res.info)
     }
+
+    async fn print(&self, message: String) -> ClientResult<()> {
+        println!("{}", message);
+        let mut state = self.state.write().unwrap();
+        state.outputs.push(message);
+        Ok(())
+    }
+
+    async fn input_text(&self, prompt: String, multiline: bool) -> ClientResult<String> {
+        let mut value = String::new();
+        if multiline {
+            println!("{}", &prompt);
+            if cfg!(windows) {
+                println!("(Ctrl+Z to exit)");
+            } else {
+                println!("(Ctrl+D to exit)");
+            }
+            io::stdin().read_to_string(&mut value)
+                .map_err(|e| Error::browser_callback_failed(format!("input error: {}", e)))?;
+            println!();
+        } else {
+            value = terminal_input(&prompt, |_val| Ok(()));
+        }
+        Ok(value)
+    }
+
+    async fn select_menu(
+        &self,
+        title: String,
+        description: String,
+        items: Vec<MenuItem>,
+    ) -> ClientResult<u32> {
+        println!("{}", title);
+        println!("{}", description);
+        for (i, item) in items.iter().enumerate() {
+            println!("{}) {}", i + 1, item.title);
+            if !item.description.is_empty() {
+                println!("   {}", item.description);
+            }
+        }
+        let mut index = 0;
+        let _ = terminal_input("Select action:", |val| {
+            index = val.parse::<usize>().map_err(|e| format!("{}", e))?;
+            if index == 0 || index > items.len() {
+                return Err("invalid action number".to_string());
+            }
+            Ok(())
+        });
+        Ok(index as u32 - 1)
+    }
+
+    async fn input_address(&self, prompt: String) -> ClientResult<String> {
+        Ok(terminal_input(&prompt, |_val| Ok(())))
+    }
+
+    async fn select_wallet(&self) -> ClientResult<String> {
+        Ok(terminal_input("Enter wallet address:", |_val| Ok(())))
+    }
+
+    async fn input_amount(
+        &self,
+        prompt: String,
+        decimals: u8,
+        _min: String,
+        _max: String,
+    ) -> ClientResult<String> {
+        let prompt = format!("{} (decimals: {})", prompt, decimals);
+        Ok(terminal_input(&prompt, |_val| Ok(())))
+    }
+
+    async fn confirm(&self, prompt: String) -> ClientResult<bool> {
+        let mut confirmed = false;
+        let _ = terminal_input(&format!("{} (y/n)", prompt), |val| {
+            confirmed = match val.as_str() {
+                "y" => true,
+                "n" => false,
+                _ => return Err("invalid enter".to_string()),
+            };
+            Ok(())
+        });
+        Ok(confirmed)
+    }
+
+    async fn input_number(&self, prompt: String, min: String, max: String) -> ClientResult<String> {
+        let prompt = format!("{} [{}, {}]", prompt, min, max);
+        Ok(terminal_input(&prompt, |_val| Ok(())))
+    }
+
+    async fn input_datetime(
+        &self,
+        prompt: String,
+        kind: DateTimeKind,
+        default: String,
+        min: String,
+        max: String,
+    ) -> ClientResult<String> {
+        let prompt = format!("{} ({:?}, default {}) [{}, {}]", prompt, kind, default, min, max);
+        let value = terminal_input(&prompt, |_val| Ok(()));
+        Ok(if value.is_empty() { default } else { value })
+    }
+
+    async fn get_time_zone_offset(&self) -> ClientResult<i16> {
+        Ok(0)
+    }
+
+    async fn select_key(&self, prompt: String, public_keys: Vec<String>) -> ClientResult<String> {
+        println!("{}", prompt);
+        for (i, key) in public_keys.iter().enumerate() {
+            println!("{}) {}", i + 1, key);
+        }
+        let mut index = 0;
+        let _ = terminal_input("Select key:", |val| {
+            index = val.parse::<usize>().map_err(|e| format!("{}", e))?;
+            if index == 0 || index > public_keys.len() {
+                return Err("invalid key number".to_string());
+            }
+            Ok(())
+        });
+        Ok(public_keys[index - 1].clone())
+    }
+
+    async fn scan_qr_code(&self, prompt: String) -> ClientResult<String> {
+        Ok(terminal_input(&prompt, |_val| Ok(())))
+    }
+
+    async fn output_media(&self, prompt: String, data: String) -> ClientResult<()> {
+        println!("{}", prompt);
+        println!("[media: {} bytes]", data.len());
+        Ok(())
+    }
 }
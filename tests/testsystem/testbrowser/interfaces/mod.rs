@@ -1,9 +1,3 @@
 mod echo;
-mod terminal;
-mod signing_box_input;
-mod encryption_box_input;
 
-pub use terminal::Terminal;
-pub use signing_box_input::SigningBoxInput;
-pub use encryption_box_input::EncryptionBoxInput;
 pub use echo::Echo;
\ No newline at end of file
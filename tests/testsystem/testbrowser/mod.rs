@@ -4,7 +4,6 @@ mod term_browser;
 mod callbacks;
 mod helpers;
 mod interfaces;
-mod encryption_box;
 
 pub use helpers::*;
 pub use config::Config;
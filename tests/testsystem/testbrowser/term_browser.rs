@@ -36,6 +36,8 @@ struct TerminalBrowser {
     /// Browser exit argument. Initialized only if DeBot sends message to the DeBot Browser address.
     pub exit_arg: Option<serde_json::Value>,
     callbacks: Arc<Callbacks>,
+    /// User keys available to DeBots.
+    keys: KeyProviderRef,
 }
 
 impl TerminalBrowser {
@@ -45,7 +47,8 @@ impl TerminalBrowser {
             client: client.clone(),
             msg_queue: Default::default(),
             bots: HashMap::new(),
-            interfaces: SupportedInterfaces::new(client, debot_key, callbacks.clone()),
+            interfaces: SupportedInterfaces::new(client.clone(), callbacks.clone()),
+            keys: Arc::new(KeyPairProvider::new(client, vec![debot_key])),
             callbacks,
             config,
             interactive: false,
@@ -64,6 +67,7 @@ impl TerminalBrowser {
             self.client.clone(),
             self.callbacks.clone(),
         );
+        dengine.set_key_provider(self.keys.clone());
         let info: DebotInfo = dengine.init().await?.into();
         let abi_version = info.dabi_version.clone();
         let abi: Abi = Abi::Contract(serde_json::from_str::<AbiContract>(info.dabi.as_ref().unwrap()).unwrap());